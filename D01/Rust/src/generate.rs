@@ -0,0 +1,67 @@
+use common::generate::{Generator, Rng};
+
+use crate::Day01;
+
+/// Shape of a generated pair of location lists.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// Number of lines, each holding one ID from both lists.
+    pub lines: usize,
+    /// Percentage of right-hand IDs copied from the left list, so that the
+    /// similarity score in part two is not zero.
+    pub repeats: u64,
+}
+
+impl Generator for Day01 {
+    type Config = Config;
+
+    const SIZE: usize = 1000;
+
+    fn config(size: usize) -> Config {
+        Config {
+            lines: size,
+            repeats: 20,
+        }
+    }
+
+    fn generate(rng: &mut Rng, config: &Config) -> String {
+        let left = (0..config.lines)
+            .map(|_| rng.range(10_000..=99_999))
+            .collect::<Vec<_>>();
+
+        left.iter()
+            .map(|l| {
+                let r = if rng.percent(config.repeats) {
+                    *rng.pick(&left)
+                } else {
+                    rng.range(10_000..=99_999)
+                };
+
+                format!("{l}   {r}\n")
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{
+        generate::{Generator, Rng},
+        Solution,
+    };
+
+    use crate::Day01;
+
+    #[test]
+    fn generate_pairs() {
+        let config = Day01::config(50);
+        let input = Day01::generate(&mut Rng::new(3), &config);
+
+        assert_eq!(input, Day01::generate(&mut Rng::new(3), &config));
+        assert_eq!(50, input.lines().count());
+
+        let (left, right) = Day01::parse(&input).unwrap();
+        assert_eq!(left.len(), right.len());
+        assert!(Day01::part_two(&(left, right)) > 0);
+    }
+}
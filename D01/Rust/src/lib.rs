@@ -0,0 +1,109 @@
+pub mod generate;
+
+use common::{parse::parse_number, ParseError, Solution};
+use itertools::Itertools;
+
+pub struct Day01;
+
+fn parse_line(line: &str) -> Result<(i32, i32), ParseError> {
+    let mut tokens = line.split_whitespace();
+
+    let (Some(left), Some(right)) = (tokens.next(), tokens.next()) else {
+        return Err(ParseError::token(
+            line,
+            line.trim_end(),
+            "expected two numbers",
+        ));
+    };
+
+    if let Some(extra) = tokens.next() {
+        return Err(ParseError::token(line, extra, "expected only two numbers"));
+    }
+
+    Ok((parse_number(line, left)?, parse_number(line, right)?))
+}
+
+fn parse(input: &str) -> Result<(Vec<i32>, Vec<i32>), ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| parse_line(line).map_err(|e| e.on_line(idx + 1)))
+        .process_results(|pairs| pairs.unzip())
+}
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = (Vec<i32>, Vec<i32>);
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one((left, right): &Self::Input) -> usize {
+        let mut left = left.clone();
+        let mut right = right.clone();
+
+        left.sort_unstable();
+        right.sort_unstable();
+
+        left.into_iter()
+            .zip(right)
+            .fold(0, |acc, (a, b)| acc + a.abs_diff(b) as usize)
+    }
+
+    fn part_two((left, right): &Self::Input) -> usize {
+        let right_counts = right.iter().counts();
+
+        left.iter().fold(0, |acc, l| {
+            acc + (*l as usize) * right_counts.get(l).unwrap_or(&0)
+        })
+    }
+}
+
+pub fn solve_part_one(input: &str) -> Result<usize, ParseError> {
+    Ok(Day01::part_one(&parse(input)?))
+}
+
+pub fn solve_part_two(input: &str) -> Result<usize, ParseError> {
+    Ok(Day01::part_two(&parse(input)?))
+}
+
+#[cfg(test)]
+mod test {
+    use common::ParseError;
+
+    static SAMPLE: &str = r"3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+
+    #[test]
+    fn solve_sample_one() {
+        let expected = 11;
+        let actual = super::solve_part_one(SAMPLE).unwrap();
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn solve_sample_two() {
+        let expected = 31;
+        let actual = super::solve_part_two(SAMPLE).unwrap();
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn reject_malformed_lines() {
+        let error = super::solve_part_one("3   4\n4   x3\n2").unwrap_err();
+        assert_eq!(ParseError::new(2, 5, "x3", "expected a number"), error);
+
+        let error = super::solve_part_one("3   4\n2").unwrap_err();
+        assert_eq!(ParseError::new(2, 1, "2", "expected two numbers"), error);
+    }
+}
@@ -0,0 +1,18 @@
+use std::path::Path;
+
+use common::{
+    bench::{bench_input, bench_solution, generated},
+    Solution,
+};
+use criterion::{criterion_group, criterion_main, Criterion};
+use d02::Day02;
+
+fn bench_puzzle_input(c: &mut Criterion) {
+    let input_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../input.txt");
+    let input = bench_input(Day02::DAY, &[input_path], generated::<Day02>);
+
+    bench_solution::<Day02>(c, &input);
+}
+
+criterion_group!(benches, bench_puzzle_input);
+criterion_main!(benches);
@@ -0,0 +1,84 @@
+use std::ops::RangeInclusive;
+
+use common::generate::{Generator, Rng};
+
+use crate::Day02;
+
+/// Shape of generated reactor reports.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub reports: usize,
+    /// Number of levels in each report.
+    pub levels: RangeInclusive<u64>,
+    /// Percentage of reports with one bad step: a flat step, a jump of more
+    /// than three, or a change of direction.
+    pub faults: u64,
+}
+
+impl Generator for Day02 {
+    type Config = Config;
+
+    const SIZE: usize = 1000;
+
+    fn config(size: usize) -> Config {
+        Config {
+            reports: size,
+            levels: 5..=8,
+            faults: 50,
+        }
+    }
+
+    fn generate(rng: &mut Rng, config: &Config) -> String {
+        (0..config.reports)
+            .map(|_| {
+                let levels = rng.range(config.levels.clone()) as usize;
+                let increasing = rng.percent(50);
+                let fault = rng.percent(config.faults).then(|| rng.index(levels));
+
+                let mut level = if increasing {
+                    rng.range(1..=40) as i64
+                } else {
+                    rng.range(60..=99) as i64
+                };
+                let mut report = Vec::with_capacity(levels);
+
+                for idx in 0..levels {
+                    report.push(level.to_string());
+
+                    let step = match fault {
+                        Some(at) if at == idx => *rng.pick(&[0, 4, 5, -2]),
+                        _ => rng.range(1..=3) as i64,
+                    };
+                    level += if increasing { step } else { -step };
+                }
+
+                report.join(" ") + "\n"
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{
+        generate::{Generator, Rng},
+        Solution,
+    };
+
+    use crate::Day02;
+
+    #[test]
+    fn generate_reports() {
+        let config = Day02::config(200);
+        let input = Day02::generate(&mut Rng::new(5), &config);
+
+        assert_eq!(input, Day02::generate(&mut Rng::new(5), &config));
+
+        let reports = Day02::parse(&input).unwrap();
+        assert_eq!(200, reports.len());
+        assert!(reports.iter().flatten().all(|&level| level > 0));
+
+        let safe = Day02::part_one(&reports);
+        assert!(0 < safe && safe < Day02::part_two(&reports));
+    }
+}
@@ -0,0 +1,108 @@
+pub mod generate;
+
+use common::{parse::parse_number, ParseError, Solution};
+
+pub struct Day02;
+
+fn process_records(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty())
+        .map(|(idx, l)| {
+            l.split_whitespace()
+                .map(|x| parse_number(l, x))
+                .collect::<Result<_, _>>()
+                .map_err(|e| e.on_line(idx + 1))
+        })
+        .collect()
+}
+
+fn compare_pair_with_bounds(a: i32, b: i32) -> bool {
+    a <= b && (1..=3).contains(&(a - b).abs())
+}
+
+fn is_record_safe(record: &[i32]) -> bool {
+    record
+        .iter()
+        .is_sorted_by(|a, b| compare_pair_with_bounds(**a, **b))
+        || record
+            .iter()
+            .is_sorted_by(|a, b| compare_pair_with_bounds(**b, **a))
+}
+
+fn is_record_safe_skip(record: &[i32], skip_index: usize) -> bool {
+    let filtered: Vec<i32> = record
+        .iter()
+        .enumerate()
+        .filter_map(|(i, &x)| if i == skip_index { None } else { Some(x) })
+        .collect();
+
+    is_record_safe(&filtered)
+}
+
+fn is_record_safe_anywhere(record: &[i32]) -> bool {
+    (0..record.len()).any(|idx| is_record_safe_skip(record, idx))
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec<i32>>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        process_records(input)
+    }
+
+    fn part_one(records: &Self::Input) -> usize {
+        records.iter().filter(|r| is_record_safe(r)).count()
+    }
+
+    fn part_two(records: &Self::Input) -> usize {
+        records
+            .iter()
+            .filter(|r| is_record_safe(r) || is_record_safe_anywhere(r))
+            .count()
+    }
+}
+
+pub fn solve_part_one(input: &str) -> Result<usize, ParseError> {
+    Ok(Day02::part_one(&process_records(input)?))
+}
+
+pub fn solve_part_two(input: &str) -> Result<usize, ParseError> {
+    Ok(Day02::part_two(&process_records(input)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use common::ParseError;
+
+    static SAMPLE: &str = r"7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9";
+
+    #[test]
+    fn test_sample_one() {
+        let expected = 2;
+        let actual = super::solve_part_one(SAMPLE).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_sample_two() {
+        let expected = 4;
+        let actual = super::solve_part_two(SAMPLE).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_rejects_non_numbers() {
+        let error = super::solve_part_one("7 6 4\n1 2 - 8").unwrap_err();
+        assert_eq!(ParseError::new(2, 5, "-", "expected a number"), error);
+    }
+}
@@ -0,0 +1,111 @@
+use common::generate::{Generator, Rng};
+
+use crate::Day03;
+
+/// Shape of generated corrupted memory.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// Number of instructions, valid or corrupted.
+    pub instructions: usize,
+    /// Instructions per line of output.
+    pub per_line: usize,
+}
+
+static NOISE: &[&str] = &[
+    "mul[",
+    "mul (",
+    "mul(4*",
+    "mul(6,9!",
+    "?(12,34)",
+    "mul ( 2 , 4 )",
+    "don't",
+    "do(",
+    "what()",
+    "select()",
+    "from()",
+    "who()",
+    "how(",
+    "where(",
+    "when()",
+    "#",
+    "%",
+    "&",
+    "<",
+    ">",
+    "'",
+    "]",
+    "^",
+    "+",
+    "-",
+    "@",
+    " ",
+    ",",
+    ")",
+    "{",
+    "}",
+    ";",
+    ":",
+    "~",
+    "!",
+    "$",
+    "*",
+    "/",
+];
+
+impl Generator for Day03 {
+    type Config = Config;
+
+    const SIZE: usize = 1500;
+
+    fn config(size: usize) -> Config {
+        Config {
+            instructions: size,
+            per_line: 250,
+        }
+    }
+
+    fn generate(rng: &mut Rng, config: &Config) -> String {
+        let mut memory = String::new();
+
+        for idx in 0..config.instructions {
+            if idx > 0 && idx % config.per_line.max(1) == 0 {
+                memory.push('\n');
+            }
+
+            match rng.below(10) {
+                0..=4 => {
+                    let (a, b) = (rng.range(1..=999), rng.range(1..=999));
+                    memory += &format!("mul({a},{b})");
+                }
+                5 => memory += "do()",
+                6 => memory += "don't()",
+                _ => memory.push_str(rng.pick::<&str>(NOISE)),
+            }
+        }
+
+        memory.push('\n');
+        memory
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{
+        generate::{Generator, Rng},
+        Solution,
+    };
+
+    use crate::Day03;
+
+    #[test]
+    fn generate_memory() {
+        let config = Day03::config(600);
+        let input = Day03::generate(&mut Rng::new(11), &config);
+
+        assert_eq!(input, Day03::generate(&mut Rng::new(11), &config));
+        assert_eq!(3, input.lines().count());
+
+        let memory = Day03::parse(&input).unwrap();
+        assert!(Day03::part_one(&memory) > Day03::part_two(&memory));
+    }
+}
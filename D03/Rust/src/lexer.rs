@@ -17,7 +17,7 @@ pub struct Lexer<'s> {
 
 impl Lexer<'_> {
     #[must_use]
-    pub const fn new(source: &str) -> Lexer {
+    pub const fn new(source: &str) -> Lexer<'_> {
         Lexer {
             source,
             read_position: 0,
@@ -36,16 +36,21 @@ impl Lexer<'_> {
         self.source
             .chars()
             .skip(self.read_position)
-            .zip(word.chars())
-            .all(|(a, b)| a == b)
+            .take(word.len())
+            .eq(word.chars())
     }
 
-    fn read_number(&self) -> u32 {
+    /// Reads the digits at the cursor, returning their value, or `None` if it
+    /// does not fit in a `u32`, and how many digits there were.
+    fn read_number(&self) -> (Option<u32>, usize) {
         self.source
             .chars()
             .skip(self.read_position)
-            .take_while(char::is_ascii_digit)
-            .fold(0, |acc, x| acc * 10 + x.to_digit(10).unwrap())
+            .map_while(|c| c.to_digit(10))
+            .fold((Some(0), 0), |(acc, len), digit| {
+                let value = acc.and_then(|acc: u32| acc.checked_mul(10)?.checked_add(digit));
+                (value, len + 1)
+            })
     }
 }
 
@@ -67,13 +72,9 @@ impl Iterator for Lexer<'_> {
                 Some(Token::Do)
             }
             Some('0'..='9') => {
-                let value = self.read_number();
-                self.advance(if value == 0 {
-                    1
-                } else {
-                    value.ilog10() as usize + 1
-                });
-                Some(Token::Num(value))
+                let (value, length) = self.read_number();
+                self.advance(length);
+                Some(value.map_or(Token::Other, Token::Num))
             }
             Some('(') => {
                 self.advance(1);
@@ -95,3 +96,38 @@ impl Iterator for Lexer<'_> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Lexer, Token};
+
+    #[test]
+    fn numbers_consume_every_digit() {
+        let tokens = Lexer::new("007,12").collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![Token::Num(7), Token::Separator, Token::Num(12)],
+            tokens
+        );
+    }
+
+    #[test]
+    fn oversized_numbers_are_not_numbers() {
+        let tokens = Lexer::new("99999999999)").collect::<Vec<_>>();
+
+        assert_eq!(vec![Token::Other, Token::RightParens], tokens);
+    }
+
+    #[test]
+    fn truncated_words_do_not_match() {
+        assert_eq!(
+            vec![Token::Other, Token::Other],
+            Lexer::new("mu").collect::<Vec<_>>()
+        );
+        assert_eq!(vec![Token::Do], Lexer::new("do").collect::<Vec<_>>());
+        assert_eq!(
+            vec![Token::Do, Token::Other],
+            Lexer::new("don").collect::<Vec<_>>()
+        );
+    }
+}
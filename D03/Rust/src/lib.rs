@@ -1,2 +1,78 @@
+pub mod generate;
 pub mod lexer;
 pub mod parser;
+
+use common::{ParseError, Solution};
+use lexer::Lexer;
+use parser::{Expression, Parser};
+
+pub struct Day03;
+
+fn sum_multiplications(input: &str, conditions_enabled: bool) -> u64 {
+    let mut lexer = Lexer::new(input);
+    let parser = Parser::new(&mut lexer, conditions_enabled);
+
+    parser.fold(0, |acc, expr| match expr {
+        Expression::Multiply(first, second) => acc + u64::from(first) * u64::from(second),
+        Expression::Nop => acc,
+    })
+}
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    // The lexer works directly on the corrupted memory, so there is nothing to
+    // parse up front and no input can be malformed.
+    type Input = String;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_one(input: &Self::Input) -> u64 {
+        sum_multiplications(input, false)
+    }
+
+    fn part_two(input: &Self::Input) -> u64 {
+        sum_multiplications(input, true)
+    }
+}
+
+pub fn solve_part_one(input: &str) -> u64 {
+    sum_multiplications(input, false)
+}
+
+pub fn solve_part_two(input: &str) -> u64 {
+    sum_multiplications(input, true)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_sample_one() {
+        const SAMPLE: &str =
+            "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+
+        let expected = 161;
+        let actual = super::solve_part_one(SAMPLE);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_sample_two() {
+        const SAMPLE: &str =
+            "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+        let expected = 48;
+        let actual = super::solve_part_two(SAMPLE);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_large_operands_do_not_overflow() {
+        let expected = 10_000_000_000;
+        let actual = super::solve_part_one("mul(100000,100000)mul(99999999999,1)");
+        assert_eq!(expected, actual);
+    }
+}
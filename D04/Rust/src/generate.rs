@@ -0,0 +1,58 @@
+use common::generate::{Generator, Rng};
+
+use crate::Day04;
+
+/// Shape of a generated word search.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub rows: usize,
+    pub cols: usize,
+}
+
+impl Generator for Day04 {
+    type Config = Config;
+
+    const SIZE: usize = 140;
+
+    fn config(size: usize) -> Config {
+        Config {
+            rows: size.max(1),
+            cols: size.max(1),
+        }
+    }
+
+    fn generate(rng: &mut Rng, config: &Config) -> String {
+        (0..config.rows)
+            .map(|_| {
+                let mut row = (0..config.cols)
+                    .map(|_| *rng.pick(&['X', 'M', 'A', 'S']))
+                    .collect::<String>();
+                row.push('\n');
+                row
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{
+        generate::{Generator, Rng},
+        Solution,
+    };
+
+    use crate::Day04;
+
+    #[test]
+    fn generate_word_search() {
+        let config = Day04::config(30);
+        let input = Day04::generate(&mut Rng::new(2), &config);
+
+        assert_eq!(input, Day04::generate(&mut Rng::new(2), &config));
+
+        let table = Day04::parse(&input).unwrap();
+        assert_eq!((30, 30), (table.rows(), table.cols()));
+        assert!(Day04::part_one(&table) > 0);
+        assert!(Day04::part_two(&table) > 0);
+    }
+}
@@ -0,0 +1,149 @@
+pub mod generate;
+
+use common::{ParseError, Solution};
+use grid::{Grid, Position, DIRECTIONS};
+
+static XMAS: &str = "XMAS";
+
+pub struct Day04;
+
+pub type Table = Grid<char>;
+
+fn parse_table(input: &str) -> Result<Table, ParseError> {
+    Grid::parse(input, |c| c)
+}
+
+fn scan_table(table: &Table, position: Position) -> usize {
+    if table[position] != 'X' {
+        0
+    } else {
+        DIRECTIONS
+            .iter()
+            .filter(|(dx, dy)| {
+                XMAS.chars().enumerate().all(|(step, ch)| {
+                    let step = step as isize;
+
+                    table.neighbour(position, (dx * step, dy * step)) == Some(&ch)
+                })
+            })
+            .count()
+    }
+}
+
+fn scan_table_cross(table: &Table, position: Position) -> bool {
+    if table[position] != 'A' {
+        return false;
+    }
+
+    let corners = [(-1, -1), (-1, 1), (1, -1), (1, 1)] // tl, tr, bl, br
+        .map(|offset| table.neighbour(position, offset).copied());
+
+    let [Some(tl), Some(tr), Some(bl), Some(br)] = corners else {
+        return false;
+    };
+
+    let diag1 = format!("{}A{}", tl, br);
+    let diag2 = format!("{}A{}", tr, bl);
+
+    (diag1 == "MAS" || diag1 == "SAM") && (diag2 == "MAS" || diag2 == "SAM")
+}
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Table;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_table(input)
+    }
+
+    fn part_one(table: &Self::Input) -> usize {
+        table
+            .positions()
+            .fold(0, |acc, position| acc + scan_table(table, position))
+    }
+
+    fn part_two(table: &Self::Input) -> usize {
+        table
+            .positions()
+            .filter(|&position| scan_table_cross(table, position))
+            .count()
+    }
+}
+
+pub fn solve_part_one(input: &str) -> Result<usize, ParseError> {
+    Ok(Day04::part_one(&parse_table(input)?))
+}
+
+pub fn solve_part_two(input: &str) -> Result<usize, ParseError> {
+    Ok(Day04::part_two(&parse_table(input)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use common::ParseError;
+
+    static SMALL: &str = r"..X...
+.SAMX.
+.A..A.
+XMAS.S
+.X....";
+
+    static SAMPLE: &str = r"MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+
+    #[test]
+    fn test_solve_small_one() {
+        let expected = 4;
+
+        let actual = super::solve_part_one(SMALL).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_solve_small_two() {
+        let expected = 0;
+
+        let actual = super::solve_part_two(SMALL).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_solve_one() {
+        let expected = 18;
+
+        let actual = super::solve_part_one(SAMPLE).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_solve_two() {
+        let expected = 9;
+
+        let actual = super::solve_part_two(SAMPLE).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_rejects_truncated_row() {
+        let error = super::solve_part_one("XMAS\nXMA\nXMAS").unwrap_err();
+
+        assert_eq!(
+            ParseError::new(2, 4, "XMA", "expected 4 columns but found 3"),
+            error
+        );
+    }
+}
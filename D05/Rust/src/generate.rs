@@ -0,0 +1,92 @@
+use std::ops::RangeInclusive;
+
+use common::generate::{Generator, Rng};
+
+use crate::Day05;
+
+/// Shape of generated ordering rules and updates.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// Number of distinct two-digit page numbers, at most 90. Every pair of
+    /// pages gets a rule, like the real inputs.
+    pub pages: usize,
+    pub updates: usize,
+    /// Pages per update; even lengths are rounded down to keep a middle page.
+    pub length: RangeInclusive<u64>,
+    /// Percentage of updates that are already correctly ordered.
+    pub ordered: u64,
+}
+
+impl Generator for Day05 {
+    type Config = Config;
+
+    const SIZE: usize = 200;
+
+    fn config(size: usize) -> Config {
+        Config {
+            pages: 49,
+            updates: size,
+            length: 5..=23,
+            ordered: 50,
+        }
+    }
+
+    fn generate(rng: &mut Rng, config: &Config) -> String {
+        let mut pages = (10..100).collect::<Vec<u64>>();
+        rng.shuffle(&mut pages);
+        pages.truncate(config.pages.min(90));
+
+        let mut rules = Vec::new();
+        for (i, before) in pages.iter().enumerate() {
+            for after in &pages[i + 1..] {
+                rules.push(format!("{before}|{after}\n"));
+            }
+        }
+        rng.shuffle(&mut rules);
+
+        let updates = (0..config.updates).map(|_| {
+            let length = rng.range(config.length.clone()).max(1) as usize;
+            let length = (length - 1 + length % 2).clamp(1, pages.len());
+
+            let mut positions = (0..pages.len()).collect::<Vec<_>>();
+            rng.shuffle(&mut positions);
+            positions.truncate(length);
+
+            if rng.percent(config.ordered) {
+                positions.sort_unstable();
+            }
+
+            let update = positions
+                .iter()
+                .map(|&position| pages[position].to_string())
+                .collect::<Vec<_>>();
+
+            update.join(",") + "\n"
+        });
+
+        rules.concat() + "\n" + &updates.collect::<String>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{
+        generate::{Generator, Rng},
+        Solution,
+    };
+
+    use crate::Day05;
+
+    #[test]
+    fn generate_rules_and_updates() {
+        let config = Day05::config(40);
+        let input = Day05::generate(&mut Rng::new(13), &config);
+
+        assert_eq!(input, Day05::generate(&mut Rng::new(13), &config));
+
+        let (graph, updates) = Day05::parse(&input).unwrap();
+        assert_eq!(40, updates.len());
+        assert!(updates.iter().all(|update| update.len() % 2 == 1));
+        assert!(Day05::part_one(&(graph, updates)) > 0);
+    }
+}
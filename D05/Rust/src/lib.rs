@@ -0,0 +1,176 @@
+pub mod generate;
+
+use std::cmp::Ordering;
+
+use common::{parse::parse_number, ParseError, Solution};
+use itertools::Itertools;
+
+pub struct Day05;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Node(i32);
+
+#[derive(Debug)]
+pub struct Edge(Node, Node);
+
+#[derive(Debug, Default)]
+pub struct Graph(Vec<Edge>);
+
+impl Graph {
+    fn ordering(&self, n1: Node, n2: Node) -> Ordering {
+        if self.0.iter().any(|Edge(from, to)| *from == n1 && *to == n2) {
+            Ordering::Less
+        } else if self.0.iter().any(|Edge(from, to)| *from == n2 && *to == n1) {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
+
+fn parse_edge(line: &str, (left, right): (&str, &str)) -> Result<Edge, ParseError> {
+    Ok(Edge(
+        Node(parse_number(line, left)?),
+        Node(parse_number(line, right)?),
+    ))
+}
+
+fn parse_update(line: &str) -> Result<Vec<Node>, ParseError> {
+    line.split(',')
+        .map(|x| parse_number(line, x).map(Node))
+        .collect()
+}
+
+pub fn parse(input: &str) -> Result<(Graph, Vec<Vec<Node>>), ParseError> {
+    let mut edges = Vec::new();
+    let mut updates = Vec::new();
+
+    for (idx, line) in input.lines().enumerate() {
+        let line = line.trim_end();
+        let on_line = |e: ParseError| e.on_line(idx + 1);
+
+        if line.is_empty() {
+            continue;
+        } else if let Some(pair) = line.split_once('|') {
+            edges.push(parse_edge(line, pair).map_err(on_line)?);
+        } else {
+            updates.push(parse_update(line).map_err(on_line)?);
+        }
+    }
+
+    Ok((Graph(edges), updates))
+}
+
+fn is_ordered(update: &[Node], graph: &Graph) -> bool {
+    update.is_sorted_by(|a, b| graph.ordering(*a, *b) != Ordering::Greater)
+}
+
+pub fn solve_part_one(graph: &Graph, updates: &[Vec<Node>]) -> i32 {
+    updates.iter().fold(0, |acc, update| {
+        acc + if is_ordered(update, graph) {
+            update.get(update.len() / 2).unwrap().0
+        } else {
+            0
+        }
+    })
+}
+
+pub fn solve_part_two(graph: &Graph, updates: &[Vec<Node>]) -> i32 {
+    updates.iter().fold(0, |acc, update| {
+        acc + if !is_ordered(update, graph) {
+            update
+                .iter()
+                .sorted_by(|&&a, &&b| graph.ordering(a, b))
+                .nth(update.len() / 2)
+                .unwrap()
+                .0
+        } else {
+            0
+        }
+    })
+}
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = (Graph, Vec<Vec<Node>>);
+    type Answer = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one((graph, updates): &Self::Input) -> i32 {
+        solve_part_one(graph, updates)
+    }
+
+    fn part_two((graph, updates): &Self::Input) -> i32 {
+        solve_part_two(graph, updates)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::ParseError;
+
+    static SAMPLE: &str = r"47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+
+    #[test]
+    fn test_solve_one() {
+        let expected = 143;
+
+        let (g, t) = super::parse(SAMPLE).unwrap();
+
+        let actual = super::solve_part_one(&g, &t);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_solve_two() {
+        let expected = 123;
+
+        let (g, t) = super::parse(SAMPLE).unwrap();
+
+        let actual = super::solve_part_two(&g, &t);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_rejects_malformed_lines() {
+        let error = super::parse("47|53\n97|1x3\n\n75,47").unwrap_err();
+        assert_eq!(ParseError::new(2, 4, "1x3", "expected a number"), error);
+
+        let error = super::parse("47|53\n\n75,47,\n").unwrap_err();
+        assert_eq!(ParseError::new(3, 7, "", "expected a number"), error);
+    }
+}
@@ -0,0 +1,90 @@
+use common::generate::{Generator, Rng};
+
+use crate::{causes_loop, get_start_position, parse_grid, Day06};
+
+/// Shape of a generated lab map.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// Rows of the map, at least one.
+    pub rows: usize,
+    /// Columns of the map, at least one.
+    pub cols: usize,
+    /// Percentage of cells holding an obstacle.
+    pub obstacles: u64,
+}
+
+impl Generator for Day06 {
+    type Config = Config;
+
+    const SIZE: usize = 130;
+
+    fn config(size: usize) -> Config {
+        Config {
+            rows: size.max(1),
+            cols: size.max(1),
+            obstacles: 5,
+        }
+    }
+
+    /// Maps are redrawn until the guard walks off the edge, as the puzzle
+    /// promises for part one.
+    fn generate(rng: &mut Rng, config: &Config) -> String {
+        // A position no guard can step onto, so `causes_loop` only sees the
+        // obstacles already on the map.
+        let nowhere = (usize::MAX, usize::MAX);
+        let (rows, cols) = (config.rows.max(1), config.cols.max(1));
+
+        loop {
+            let mut cells = (0..rows * cols)
+                .map(|_| {
+                    if rng.percent(config.obstacles) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            let empty = (0..cells.len())
+                .filter(|&idx| cells[idx] == '.')
+                .collect::<Vec<_>>();
+            if empty.is_empty() {
+                continue;
+            }
+            cells[*rng.pick(&empty)] = '^';
+
+            let map = cells
+                .chunks(cols)
+                .map(|row| row.iter().collect::<String>() + "\n")
+                .collect::<String>();
+
+            let grid = parse_grid(&map).expect("generated map is valid");
+            if !causes_loop(&grid, get_start_position(&grid), nowhere) {
+                return map;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{
+        generate::{Generator, Rng},
+        Solution,
+    };
+
+    use crate::Day06;
+
+    #[test]
+    fn generate_escapable_map() {
+        let config = Day06::config(40);
+        let input = Day06::generate(&mut Rng::new(17), &config);
+
+        assert_eq!(input, Day06::generate(&mut Rng::new(17), &config));
+        assert_eq!(1, input.matches('^').count());
+
+        let grid = Day06::parse(&input).unwrap();
+        assert!(Day06::part_one(&grid) > 1);
+        Day06::part_two(&grid);
+    }
+}
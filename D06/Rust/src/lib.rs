@@ -1,91 +1,39 @@
-use std::collections::HashSet;
+pub mod generate;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct Cell {
-    position: (usize, usize),
-    r#type: CellType,
-}
+use std::{collections::HashSet, fmt::Display};
+
+use common::{ParseError, Solution};
+use grid::{Direction, Grid, Position, Rotation};
+
+pub struct Day06;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-enum CellType {
+pub enum Cell {
     Empty,
     Obstacle,
     Start,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-enum Direction {
-    Left,
-    Right,
-    Up,
-    Down,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Rotation {
-    Clockwise,
-    CounterClockwise,
-}
-
-impl Direction {
-    pub fn offset(self) -> (isize, isize) {
-        match self {
-            Direction::Left => (0, -1),
-            Direction::Right => (0, 1),
-            Direction::Up => (-1, 0),
-            Direction::Down => (1, 0),
-        }
-    }
-
-    pub fn rotate(self, direction: Rotation) -> Self {
-        match (self, direction) {
-            (Self::Left, Rotation::Clockwise) => Self::Up,
-            (Self::Left, Rotation::CounterClockwise) => Self::Down,
-            (Self::Up, Rotation::Clockwise) => Self::Right,
-            (Self::Up, Rotation::CounterClockwise) => Self::Left,
-            (Self::Right, Rotation::Clockwise) => Self::Down,
-            (Self::Right, Rotation::CounterClockwise) => Self::Up,
-            (Self::Down, Rotation::Clockwise) => Self::Left,
-            (Self::Down, Rotation::CounterClockwise) => Self::Right,
-        }
-    }
-}
-
 #[derive(Debug)]
 pub struct Guard {
-    position: (usize, usize),
+    position: Position,
     direction: Direction,
 }
 
 impl Guard {
-    fn new(start: (usize, usize), direction: Direction) -> Self {
+    fn new(start: Position, direction: Direction) -> Self {
         Self {
             position: start,
             direction,
         }
     }
 
-    pub fn step(&mut self, grid: &[Vec<Cell>]) -> Option<(usize, usize)> {
-        let previous_direction = self.direction;
-
-        let height = grid.len() as isize;
-        let width = grid[0].len() as isize;
-
-        let (dx, dy) = previous_direction.offset();
-        let next_x = self.position.0 as isize + dx;
-        let next_y = self.position.1 as isize + dy;
+    pub fn step(&mut self, grid: &Grid<Cell>) -> Option<Position> {
+        let next = grid.offset(self.position, self.direction.offset())?;
 
-        // Check bounds BEFORE casting to usize
-        if next_x < 0 || next_x >= height || next_y < 0 || next_y >= width {
-            return None;
-        }
-
-        let (x, y) = (next_x as usize, next_y as usize);
-        let next_cell = &grid[x][y];
-
-        if next_cell.r#type != CellType::Obstacle {
+        if grid[next] != Cell::Obstacle {
             // Can move forward
-            self.position = (x, y);
+            self.position = next;
             Some(self.position)
         } else {
             // Hit obstacle, turn right
@@ -95,46 +43,50 @@ impl Guard {
     }
 }
 
-pub fn parse_grid(input: &str) -> Vec<Vec<Cell>> {
-    let mut grid = Vec::new();
-
-    for (idx, line) in input.lines().enumerate() {
-        let cells = line
-            .chars()
-            .enumerate()
-            .map(|(jdx, c)| Cell {
-                position: (idx, jdx),
-                r#type: match c {
-                    '^' => CellType::Start,
-                    '#' => CellType::Obstacle,
-                    _ => CellType::Empty,
-                },
-            })
-            .collect();
-        grid.push(cells);
-    }
+/// The guard walks in a loop from `start` and never leaves the map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GuardTrapped {
+    pub start: Position,
+}
 
-    grid
+impl Display for GuardTrapped {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (row, col) = self.start;
+        write!(f, "guard starting at {row},{col} never leaves the map")
+    }
 }
 
-pub fn get_start_position(grid: &[Vec<Cell>]) -> (usize, usize) {
-    let mut start_position = (0, 0);
-    for idx in 0..grid.len() {
-        for jdx in 0..grid[0].len() {
-            let cur_cell = grid[idx][jdx];
-            if let CellType::Start = cur_cell.r#type {
-                start_position = cur_cell.position;
-            }
-        }
+impl std::error::Error for GuardTrapped {}
+
+pub fn parse_grid(input: &str) -> Result<Grid<Cell>, ParseError> {
+    let grid = Grid::try_parse(input, |c| match c {
+        '^' => Ok(Cell::Start),
+        '#' => Ok(Cell::Obstacle),
+        '.' => Ok(Cell::Empty),
+        _ => Err("expected `.`, `#` or `^`"),
+    })?;
+
+    let Some(start) = grid.find(|&cell| cell == Cell::Start) else {
+        return Err(ParseError::new(1, 1, "", "map has no guard `^`"));
+    };
+
+    if let Err(GuardTrapped { start: (row, col) }) = get_guard_path(&grid, start) {
+        return Err(ParseError::new(
+            row + 1,
+            col + 1,
+            "^",
+            "guard never leaves the map",
+        ));
     }
-    start_position
+
+    Ok(grid)
+}
+
+pub fn get_start_position(grid: &Grid<Cell>) -> Position {
+    grid.find(|&cell| cell == Cell::Start).unwrap_or((0, 0))
 }
 
-pub fn causes_loop(
-    grid: &[Vec<Cell>],
-    start_pos: (usize, usize),
-    obstacle_pos: (usize, usize),
-) -> bool {
+pub fn causes_loop(grid: &Grid<Cell>, start_pos: Position, obstacle_pos: Position) -> bool {
     let mut guard = Guard::new(start_pos, Direction::Up);
     let mut visited_states = HashSet::new();
 
@@ -148,22 +100,12 @@ pub fn causes_loop(
         visited_states.insert(current_state);
 
         // Check if we would step into our temporary obstacle
-        let (dx, dy) = guard.direction.offset();
-        let next_x = guard.position.0 as isize + dx;
-        let next_y = guard.position.1 as isize + dy;
-
-        if next_x < 0
-            || next_x >= grid.len() as isize
-            || next_y < 0
-            || next_y >= grid[0].len() as isize
-        {
+        let Some(next_pos) = grid.offset(guard.position, guard.direction.offset()) else {
             return false; // Guard leaves grid
-        }
-
-        let next_pos = (next_x as usize, next_y as usize);
+        };
 
         // Check if we hit our temporary obstacle or an existing one
-        if next_pos == obstacle_pos || grid[next_pos.0][next_pos.1].r#type == CellType::Obstacle {
+        if next_pos == obstacle_pos || grid[next_pos] == Cell::Obstacle {
             guard.direction = guard.direction.rotate(Rotation::Clockwise);
         } else {
             guard.position = next_pos;
@@ -171,14 +113,192 @@ pub fn causes_loop(
     }
 }
 
-pub fn get_guard_path(grid: &[Vec<Cell>], start_pos: (usize, usize)) -> HashSet<(usize, usize)> {
+/// Cells the guard visits from `start_pos` until it leaves the map, or an
+/// error if it comes back to a position facing the same way and so never
+/// leaves.
+pub fn get_guard_path(
+    grid: &Grid<Cell>,
+    start_pos: Position,
+) -> Result<HashSet<Position>, GuardTrapped> {
     let mut guard = Guard::new(start_pos, Direction::Up);
-    let mut visited_positions = HashSet::new();
-    visited_positions.insert(start_pos);
+    let mut visited_states = HashSet::from([(start_pos, Direction::Up)]);
 
     while let Some(position) = guard.step(grid) {
-        visited_positions.insert(position);
+        if !visited_states.insert((position, guard.direction)) {
+            return Err(GuardTrapped { start: start_pos });
+        }
+    }
+
+    Ok(visited_states
+        .into_iter()
+        .map(|(position, _)| position)
+        .collect())
+}
+
+/// Path of the guard on a map from `parse_grid`, which rejects maps the
+/// guard never leaves.
+fn guard_path(grid: &Grid<Cell>, start_position: Position) -> HashSet<Position> {
+    get_guard_path(grid, start_position).expect("parse_grid rejects trapped guards")
+}
+
+pub fn solve_part_one(grid: &Grid<Cell>) -> usize {
+    let start_position = get_start_position(grid);
+    let original_path = guard_path(grid, start_position);
+    original_path.len()
+}
+
+/// Cells on the guard's original path, other than the start, where a new
+/// obstacle could change the route.
+fn obstacle_candidates(grid: &Grid<Cell>, start_position: Position) -> Vec<Position> {
+    guard_path(grid, start_position)
+        .into_iter()
+        .filter(|&position| position != start_position)
+        .collect()
+}
+
+pub fn solve_part_two_serial(grid: &Grid<Cell>) -> usize {
+    let start_position = get_start_position(grid);
+
+    obstacle_candidates(grid, start_position)
+        .iter()
+        .filter(|&&position| causes_loop(grid, start_position, position))
+        .count()
+}
+
+/// `solve_part_two_serial` with each candidate obstacle tried on its own
+/// thread.
+#[cfg(feature = "parallel")]
+pub fn solve_part_two_parallel(grid: &Grid<Cell>) -> usize {
+    use rayon::prelude::*;
+
+    let start_position = get_start_position(grid);
+
+    obstacle_candidates(grid, start_position)
+        .par_iter()
+        .filter(|&&position| causes_loop(grid, start_position, position))
+        .count()
+}
+
+#[cfg(feature = "parallel")]
+pub fn solve_part_two(grid: &Grid<Cell>) -> usize {
+    solve_part_two_parallel(grid)
+}
+
+#[cfg(not(feature = "parallel"))]
+pub fn solve_part_two(grid: &Grid<Cell>) -> usize {
+    solve_part_two_serial(grid)
+}
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Grid<Cell>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_grid(input)
+    }
+
+    fn part_one(grid: &Self::Input) -> usize {
+        solve_part_one(grid)
+    }
+
+    fn part_two(grid: &Self::Input) -> usize {
+        solve_part_two(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::ParseError;
+    use grid::Grid;
+
+    use super::{Cell, GuardTrapped};
+
+    static SAMPLE: &str = r"....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+    #[test]
+    fn test_solve_one() {
+        let expected = 41;
+
+        let grid = super::parse_grid(SAMPLE).unwrap();
+
+        let actual = super::solve_part_one(&grid);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_solve_two() {
+        let expected = 6;
+
+        let grid = super::parse_grid(SAMPLE).unwrap();
+
+        let actual = super::solve_part_two(&grid);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_matches_serial() {
+        use common::generate::{Generator, Rng};
+
+        use crate::Day06;
+
+        let config = Day06::config(60);
+        let inputs = (0..4).map(|seed| Day06::generate(&mut Rng::new(seed), &config));
+
+        for input in std::iter::once(SAMPLE.to_owned()).chain(inputs) {
+            let grid = super::parse_grid(&input).unwrap();
+
+            assert_eq!(
+                super::solve_part_two_serial(&grid),
+                super::solve_part_two_parallel(&grid)
+            );
+        }
     }
 
-    visited_positions
+    #[test]
+    fn test_rejects_unknown_cells() {
+        let error = super::parse_grid("..#\n.^.\n.X.").unwrap_err();
+        assert_eq!(
+            ParseError::new(3, 2, "X", "expected `.`, `#` or `^`"),
+            error
+        );
+
+        let error = super::parse_grid("..#\n...").unwrap_err();
+        assert_eq!(ParseError::new(1, 1, "", "map has no guard `^`"), error);
+    }
+
+    #[test]
+    fn test_rejects_trapped_guard() {
+        let map = ".#.\n#^#\n.#.";
+        let grid = Grid::parse(map, |c| match c {
+            '^' => Cell::Start,
+            '#' => Cell::Obstacle,
+            _ => Cell::Empty,
+        })
+        .unwrap();
+
+        assert_eq!(
+            Err(GuardTrapped { start: (1, 1) }),
+            super::get_guard_path(&grid, (1, 1))
+        );
+
+        let error = super::parse_grid(map).unwrap_err();
+        assert_eq!(
+            ParseError::new(2, 2, "^", "guard never leaves the map"),
+            error
+        );
+    }
 }
@@ -1,26 +1,17 @@
-use d06::{causes_loop, get_guard_path, get_start_position, parse_grid, Cell};
+use std::path::Path;
 
-fn solve_part_one(grid: &[Vec<Cell>]) -> usize {
-    let start_position = get_start_position(grid);
-    let original_path = get_guard_path(grid, start_position);
-    original_path.len()
-}
-
-fn solve_part_two(grid: &[Vec<Cell>]) -> usize {
-    let start_position = get_start_position(grid);
-    let original_path = get_guard_path(grid, start_position);
-
-    original_path
-        .iter()
-        .filter(|&&position| position != start_position)
-        .filter(|&&position| causes_loop(grid, start_position, position))
-        .count()
-}
+use common::{input::read_input, Solution};
+use d06::{parse_grid, solve_part_one, solve_part_two, Day06};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let data = std::fs::read_to_string("../input.txt")?;
+    let input_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../input.txt");
+    let data = read_input(
+        Day06::DAY,
+        std::env::args().nth(1).as_deref(),
+        &[input_path],
+    )?;
 
-    let grid = parse_grid(&data);
+    let grid = parse_grid(&data)?;
 
     let p1 = solve_part_one(&grid);
 
@@ -32,39 +23,3 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
-
-#[cfg(test)]
-mod tests {
-    static SAMPLE: &str = r"....#.....
-.........#
-..........
-..#.......
-.......#..
-..........
-.#..^.....
-........#.
-#.........
-......#...";
-
-    #[test]
-    fn test_solve_one() {
-        let expected = 41;
-
-        let grid = super::parse_grid(SAMPLE);
-
-        let actual = super::solve_part_one(&grid);
-
-        assert_eq!(expected, actual);
-    }
-
-    #[test]
-    fn test_solve_two() {
-        let expected = 6;
-
-        let grid = super::parse_grid(SAMPLE);
-
-        let actual = super::solve_part_two(&grid);
-
-        assert_eq!(expected, actual);
-    }
-}
@@ -1,3 +1,9 @@
+use std::path::Path;
+
+use common::{
+    bench::{bench_input, bench_solution, generated},
+    Solution,
+};
 use criterion::{criterion_group, criterion_main, Criterion};
 use d07::*; // Assuming your functions are public in lib.rs
 
@@ -14,7 +20,7 @@ const SAMPLE: &str = r"190: 10 19
 fn bench_recursive_vs_iterative_part_one(c: &mut Criterion) {
     let mut group = c.benchmark_group("validation_methods_part_one");
 
-    let test_cases: Vec<_> = SAMPLE.lines().map(parse_line).collect();
+    let test_cases = parse(SAMPLE).unwrap();
     let part_one_ops = &[Instruction::Add, Instruction::Multiply];
 
     group.bench_function("recursive", |b| {
@@ -55,7 +61,7 @@ fn bench_recursive_vs_iterative_part_one(c: &mut Criterion) {
 fn bench_recursive_vs_iterative_part_two(c: &mut Criterion) {
     let mut group = c.benchmark_group("validation_methods_part_two");
 
-    let test_cases: Vec<_> = SAMPLE.lines().map(parse_line).collect();
+    let test_cases = parse(SAMPLE).unwrap();
     let part_two_ops = &[
         Instruction::Add,
         Instruction::Multiply,
@@ -106,7 +112,9 @@ fn bench_concatenation_methods(c: &mut Criterion) {
         b.iter(|| {
             test_pairs
                 .iter()
-                .map(|&(a, b)| concatenate_str(std::hint::black_box(a), std::hint::black_box(b)))
+                .filter_map(|&(a, b)| {
+                    concatenate_str(std::hint::black_box(a), std::hint::black_box(b))
+                })
                 .sum::<i64>()
         })
     });
@@ -115,7 +123,7 @@ fn bench_concatenation_methods(c: &mut Criterion) {
         b.iter(|| {
             test_pairs
                 .iter()
-                .map(|&(a, b)| concatenate(std::hint::black_box(a), std::hint::black_box(b)))
+                .filter_map(|&(a, b)| concatenate(std::hint::black_box(a), std::hint::black_box(b)))
                 .sum::<i64>()
         })
     });
@@ -151,6 +159,13 @@ fn bench_full_solutions_part_two(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_puzzle_input(c: &mut Criterion) {
+    let input_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../input.txt");
+    let input = bench_input(Day07::DAY, &[input_path], generated::<Day07>);
+
+    bench_solution::<Day07>(c, &input);
+}
+
 criterion_group!(
     benches,
     bench_recursive_vs_iterative_part_one,
@@ -158,5 +173,6 @@ criterion_group!(
     bench_concatenation_methods,
     bench_full_solutions_part_one,
     bench_full_solutions_part_two,
+    bench_puzzle_input,
 );
 criterion_main!(benches);
@@ -0,0 +1,102 @@
+use std::ops::RangeInclusive;
+
+use common::generate::{Generator, Rng};
+
+use crate::{concatenate, Day07};
+
+/// Operands of one equation may have at most this many digits in total, so
+/// that every combination of operators fits in an `i64`.
+const DIGIT_BUDGET: u32 = 17;
+
+/// Shape of generated calibration equations.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub equations: usize,
+    /// Operands per equation, from one up to the digit budget of 17.
+    pub operands: RangeInclusive<u64>,
+    /// Percentage of equations built so that the part two operators can
+    /// reach the target.
+    pub solvable: u64,
+}
+
+impl Generator for Day07 {
+    type Config = Config;
+
+    const SIZE: usize = 850;
+
+    fn config(size: usize) -> Config {
+        Config {
+            equations: size,
+            operands: 2..=12,
+            solvable: 50,
+        }
+    }
+
+    fn generate(rng: &mut Rng, config: &Config) -> String {
+        (0..config.equations)
+            .map(|_| {
+                let count = rng
+                    .range(config.operands.clone())
+                    .clamp(1, DIGIT_BUDGET.into());
+                let mut budget = DIGIT_BUDGET as u64;
+
+                let operands = (0..count)
+                    .map(|idx| {
+                        let reserved = count - idx - 1;
+                        let digits = rng.range(1..=(budget - reserved).min(3));
+                        budget -= digits;
+
+                        rng.range(10u64.pow(digits as u32 - 1)..=10u64.pow(digits as u32) - 1)
+                            as i64
+                    })
+                    .collect::<Vec<_>>();
+
+                let reachable =
+                    operands[1..]
+                        .iter()
+                        .fold(operands[0], |acc, &value| match rng.below(3) {
+                            0 => acc + value,
+                            1 => acc * value,
+                            _ => concatenate(acc, value).expect("operands fit the digit budget"),
+                        });
+                let target = if rng.percent(config.solvable) {
+                    reachable
+                } else {
+                    reachable + rng.range(1..=1000) as i64
+                };
+
+                let operands = operands
+                    .iter()
+                    .map(i64::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" ");
+
+                format!("{target}: {operands}\n")
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{
+        generate::{Generator, Rng},
+        Solution,
+    };
+
+    use crate::Day07;
+
+    #[test]
+    fn generate_equations() {
+        let config = Day07::config(100);
+        let input = Day07::generate(&mut Rng::new(19), &config);
+
+        assert_eq!(input, Day07::generate(&mut Rng::new(19), &config));
+
+        let equations = Day07::parse(&input).unwrap();
+        assert_eq!(100, equations.len());
+
+        let part_one = Day07::part_one(&equations);
+        assert!(0 < part_one && part_one < Day07::part_two(&equations));
+    }
+}
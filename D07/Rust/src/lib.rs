@@ -1,83 +1,118 @@
+pub mod generate;
+
 use std::collections::VecDeque;
 
-pub fn solve_part_one(input: &str) -> i64 {
+use common::{parse::parse_number, ParseError, Solution};
+
+pub struct Day07;
+
+const PART_ONE_INSTRUCTIONS: &[Instruction] = &[Instruction::Add, Instruction::Multiply];
+
+const PART_TWO_INSTRUCTIONS: &[Instruction] = &[
+    Instruction::Add,
+    Instruction::Multiply,
+    Instruction::Concatenate,
+];
+
+pub fn parse(input: &str) -> Result<Vec<(i64, Vec<i64>)>, ParseError> {
     input
         .lines()
-        .map(parse_line)
-        .fold(0, |acc, (target, values)| {
-            acc + if is_valid(
-                values[0],
-                target,
-                &values[1..],
-                &[Instruction::Add, Instruction::Multiply],
-            ) {
-                target
-            } else {
-                0
-            }
-        })
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| parse_line(line).map_err(|e| e.on_line(idx + 1)))
+        .collect()
 }
 
-pub fn solve_part_two(input: &str) -> i64 {
-    input
-        .lines()
-        .map(parse_line)
-        .fold(0, |acc, (target, values)| {
-            acc + if is_valid(
-                values[0],
-                target,
-                &values[1..],
-                &[
-                    Instruction::Add,
-                    Instruction::Multiply,
-                    Instruction::Concatenate,
-                ],
-            ) {
-                target
-            } else {
-                0
-            }
-        })
+/// Sum of the targets `instructions` can reach, as an `i128` so that adding
+/// up many large `i64` targets cannot overflow.
+pub fn total_calibration_serial(
+    equations: &[(i64, Vec<i64>)],
+    instructions: &[Instruction],
+) -> i128 {
+    equations
+        .iter()
+        .filter(|(target, values)| is_valid(values[0], *target, &values[1..], instructions))
+        .map(|(target, _)| i128::from(*target))
+        .sum()
 }
 
-pub fn solve_part_one_iterative(input: &str) -> i64 {
-    input
-        .lines()
-        .map(parse_line)
-        .fold(0, |acc, (target, values)| {
-            acc + if is_valid_iterative(
-                values[0],
-                target,
-                &values[1..],
-                &[Instruction::Add, Instruction::Multiply],
-            ) {
-                target
-            } else {
-                0
-            }
-        })
+/// `total_calibration_serial` with the equations checked on several threads.
+#[cfg(feature = "parallel")]
+pub fn total_calibration_parallel(
+    equations: &[(i64, Vec<i64>)],
+    instructions: &[Instruction],
+) -> i128 {
+    use rayon::prelude::*;
+
+    equations
+        .par_iter()
+        .filter(|(target, values)| is_valid(values[0], *target, &values[1..], instructions))
+        .map(|(target, _)| i128::from(*target))
+        .sum()
 }
 
-pub fn solve_part_two_iterative(input: &str) -> i64 {
-    input
-        .lines()
-        .map(parse_line)
-        .fold(0, |acc, (target, values)| {
-            acc + if is_valid_iterative(
-                values[0],
-                target,
-                &values[1..],
-                &[
-                    Instruction::Add,
-                    Instruction::Multiply,
-                    Instruction::Concatenate,
-                ],
-            ) {
-                target
-            } else {
-                0
-            }
+#[cfg(feature = "parallel")]
+fn total_calibration(equations: &[(i64, Vec<i64>)], instructions: &[Instruction]) -> i128 {
+    total_calibration_parallel(equations, instructions)
+}
+
+#[cfg(not(feature = "parallel"))]
+fn total_calibration(equations: &[(i64, Vec<i64>)], instructions: &[Instruction]) -> i128 {
+    total_calibration_serial(equations, instructions)
+}
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<(i64, Vec<i64>)>;
+    type Answer = i128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(equations: &Self::Input) -> i128 {
+        total_calibration(equations, PART_ONE_INSTRUCTIONS)
+    }
+
+    fn part_two(equations: &Self::Input) -> i128 {
+        total_calibration(equations, PART_TWO_INSTRUCTIONS)
+    }
+}
+
+pub fn solve_part_one(input: &str) -> Result<i128, ParseError> {
+    Ok(Day07::part_one(&parse(input)?))
+}
+
+pub fn solve_part_two(input: &str) -> Result<i128, ParseError> {
+    Ok(Day07::part_two(&parse(input)?))
+}
+
+pub fn solve_part_one_iterative(input: &str) -> Result<i128, ParseError> {
+    Ok(total_calibration_iterative(
+        &parse(input)?,
+        PART_ONE_INSTRUCTIONS,
+    ))
+}
+
+pub fn solve_part_two_iterative(input: &str) -> Result<i128, ParseError> {
+    Ok(total_calibration_iterative(
+        &parse(input)?,
+        PART_TWO_INSTRUCTIONS,
+    ))
+}
+
+fn total_calibration_iterative(
+    equations: &[(i64, Vec<i64>)],
+    instructions: &[Instruction],
+) -> i128 {
+    equations
+        .iter()
+        .filter(|(target, values)| {
+            is_valid_iterative(values[0], *target, &values[1..], instructions)
         })
+        .map(|(target, _)| i128::from(*target))
+        .sum()
 }
 
 pub enum Instruction {
@@ -86,29 +121,72 @@ pub enum Instruction {
     Concatenate,
 }
 
-pub fn parse_line(input: &str) -> (i64, Vec<i64>) {
-    let values = input.split(": ").collect::<Vec<_>>();
-    (
-        values[0].parse().unwrap(),
-        values[1]
-            .split_whitespace()
-            .filter_map(|x| x.parse().ok())
-            .collect(),
-    )
+impl Instruction {
+    /// Combines two operands, or `None` if the result overflows an `i64`, in
+    /// which case it can never come back down to a target.
+    pub fn apply(&self, first: i64, second: i64) -> Option<i64> {
+        match self {
+            Instruction::Add => first.checked_add(second),
+            Instruction::Multiply => first.checked_mul(second),
+            Instruction::Concatenate => concatenate(first, second),
+        }
+    }
+}
+
+/// Parses a `target: a b c` equation, which must have at least one value.
+/// The target and values are non-negative, as in every puzzle input.
+pub fn parse_line(input: &str) -> Result<(i64, Vec<i64>), ParseError> {
+    let (target, rest) = input
+        .split_once(':')
+        .ok_or_else(|| ParseError::token(input, input, "expected `target: values`"))?;
+
+    let values = rest
+        .split_whitespace()
+        .map(|x| parse_non_negative(input, x))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if values.is_empty() {
+        return Err(ParseError::token(
+            input,
+            rest,
+            "expected at least one value",
+        ));
+    }
+
+    Ok((parse_non_negative(input, target)?, values))
+}
+
+fn parse_non_negative(line: &str, token: &str) -> Result<i64, ParseError> {
+    let number = parse_number(line, token)?;
+
+    if number < 0 {
+        return Err(ParseError::token(
+            line,
+            token,
+            "expected a non-negative number",
+        ));
+    }
+
+    Ok(number)
 }
 
-pub fn concatenate_str(first: i64, second: i64) -> i64 {
+pub fn concatenate_str(first: i64, second: i64) -> Option<i64> {
     first
         .to_string()
         .chars()
         .chain(second.to_string().chars())
         .collect::<String>()
         .parse()
-        .unwrap()
+        .ok()
 }
 
-pub fn concatenate(first: i64, second: i64) -> i64 {
-    first * 10i64.pow(if second == 0 { 1 } else { second.ilog10() + 1 }) + second
+/// The digits of `first` followed by those of `second`, both non-negative,
+/// or `None` if that overflows an `i64`.
+pub fn concatenate(first: i64, second: i64) -> Option<i64> {
+    let digits = if second == 0 { 1 } else { second.ilog10() + 1 };
+
+    // Every `i64` times `10^19` still fits in an `i128`.
+    i64::try_from(i128::from(first) * 10i128.pow(digits) + i128::from(second)).ok()
 }
 
 pub fn is_valid(first: i64, target: i64, values: &[i64], instructions: &[Instruction]) -> bool {
@@ -120,11 +198,7 @@ pub fn is_valid(first: i64, target: i64, values: &[i64], instructions: &[Instruc
 
     instructions
         .iter()
-        .map(|i| match i {
-            Instruction::Add => first + second,
-            Instruction::Multiply => first * second,
-            Instruction::Concatenate => concatenate(first, second),
-        })
+        .filter_map(|i| i.apply(first, second))
         .any(|res| is_valid(res, target, &values[1..], instructions))
 }
 
@@ -138,6 +212,14 @@ pub fn is_valid_iterative(
         return first == target;
     }
 
+    // Adding, multiplying by or concatenating a positive operand never makes
+    // a value smaller, so once only positive operands remain a value past the
+    // target can be dropped. A later `* 0` could still bring it back down.
+    let positive_from = values
+        .iter()
+        .rposition(|&value| value < 1)
+        .map_or(0, |idx| idx + 1);
+
     let mut queue = VecDeque::from([(first, 0)]);
 
     while let Some((current, index)) = queue.pop_front() {
@@ -153,17 +235,133 @@ pub fn is_valid_iterative(
         queue.extend(
             instructions
                 .iter()
-                .map(|instruction| {
-                    let result = match instruction {
-                        Instruction::Add => current + next_value,
-                        Instruction::Multiply => current * next_value,
-                        Instruction::Concatenate => concatenate(current, next_value),
-                    };
-                    (result, index + 1)
+                .filter_map(|instruction| {
+                    Some((instruction.apply(current, next_value)?, index + 1))
                 })
-                .filter(|(result, idx)| *result <= target || *idx == values.len()),
+                .filter(|&(result, idx)| {
+                    result <= target || idx < positive_from || idx == values.len()
+                }),
         );
     }
 
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use common::{equivalence::assert_equivalent, ParseError};
+    use proptest::prelude::*;
+
+    use super::{
+        concatenate, concatenate_str, is_valid, is_valid_iterative, parse_line,
+        PART_ONE_INSTRUCTIONS, PART_TWO_INSTRUCTIONS,
+    };
+    #[cfg(feature = "parallel")]
+    use super::{total_calibration_parallel, total_calibration_serial};
+
+    /// Any operand the parser accepts, mostly small ones so that some
+    /// equations stay clear of overflow.
+    fn operand() -> impl Strategy<Value = i64> {
+        prop_oneof![4 => 0..100i64, 1 => 0..=i64::MAX]
+    }
+
+    /// Equations the parser accepts, half of them built so that the target is
+    /// reachable with the part two instructions when that does not overflow.
+    fn equation() -> impl Strategy<Value = (i64, Vec<i64>)> {
+        (
+            prop::collection::vec(operand(), 1..=6),
+            prop::collection::vec(0..3usize, 5),
+            operand(),
+            any::<bool>(),
+        )
+            .prop_map(|(values, ops, random_target, reachable)| {
+                let target = values[1..]
+                    .iter()
+                    .zip(ops)
+                    .try_fold(values[0], |acc, (&v, op)| {
+                        PART_TWO_INSTRUCTIONS[op].apply(acc, v)
+                    })
+                    .filter(|_| reachable)
+                    .unwrap_or(random_target);
+
+                (target, values)
+            })
+    }
+
+    #[test]
+    fn concatenate_matches_string_version() {
+        assert_equivalent(
+            (operand(), operand()),
+            |&(first, second)| concatenate_str(first, second),
+            |&(first, second)| concatenate(first, second),
+        );
+    }
+
+    #[test]
+    fn validators_agree() {
+        for instructions in [PART_ONE_INSTRUCTIONS, PART_TWO_INSTRUCTIONS] {
+            assert_equivalent(
+                equation(),
+                |(target, values)| is_valid(values[0], *target, &values[1..], instructions),
+                |(target, values)| {
+                    is_valid_iterative(values[0], *target, &values[1..], instructions)
+                },
+            );
+        }
+    }
+
+    #[test]
+    fn multiplying_by_zero_comes_back_down() {
+        for instructions in [PART_ONE_INSTRUCTIONS, PART_TWO_INSTRUCTIONS] {
+            assert!(is_valid(5, 0, &[3, 0], instructions));
+            assert!(is_valid_iterative(5, 0, &[3, 0], instructions));
+        }
+    }
+
+    #[test]
+    fn overflowing_results_are_dropped() {
+        let big = i64::MAX / 2 + 1;
+
+        for instructions in [PART_ONE_INSTRUCTIONS, PART_TWO_INSTRUCTIONS] {
+            assert!(!is_valid(big, 0, &[4, 1], instructions));
+            assert!(!is_valid_iterative(big, 0, &[4, 1], instructions));
+            assert!(is_valid(big, big + 1, &[1], instructions));
+        }
+        assert_eq!(None, concatenate(i64::MAX, 1));
+    }
+
+    /// Inputs that crashed the solver once they parsed.
+    #[test]
+    fn fuzz_regressions() {
+        assert!(super::solve_part_two("10: 1 -5").is_err());
+
+        let max = i64::MAX;
+        let input = format!("{max}: {max}\n{max}: {max}\n0: {max} {max} 9999999999 0");
+        let expected = 2 * i128::from(max);
+        assert_eq!(expected, super::solve_part_one(&input).unwrap());
+        assert_eq!(expected, super::solve_part_two(&input).unwrap());
+    }
+
+    #[test]
+    fn reject_negative_numbers() {
+        let error = parse_line("10: 1 -5").unwrap_err();
+
+        assert_eq!(
+            ParseError::new(1, 7, "-5", "expected a non-negative number"),
+            error
+        );
+        assert!(parse_line("-10: 1 5").is_err());
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_matches_serial() {
+        for instructions in [PART_ONE_INSTRUCTIONS, PART_TWO_INSTRUCTIONS] {
+            assert_equivalent(
+                prop::collection::vec(equation(), 0..50),
+                |equations| total_calibration_serial(equations, instructions),
+                |equations| total_calibration_parallel(equations, instructions),
+            );
+        }
+    }
+}
@@ -1,13 +1,21 @@
-use d07::{solve_part_one, solve_part_two};
+use std::path::Path;
+
+use common::{input::read_input, Solution};
+use d07::{solve_part_one, solve_part_two, Day07};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let data = std::fs::read_to_string("../input.txt")?;
+    let input_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../input.txt");
+    let data = read_input(
+        Day07::DAY,
+        std::env::args().nth(1).as_deref(),
+        &[input_path],
+    )?;
 
-    let a1 = solve_part_one(&data);
+    let a1 = solve_part_one(&data)?;
 
     println!("{a1}");
 
-    let a2 = solve_part_two(&data);
+    let a2 = solve_part_two(&data)?;
 
     println!("{a2}");
 
@@ -16,6 +24,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
 #[cfg(test)]
 mod test {
+    use common::ParseError;
+
     static SAMPLE: &str = r"190: 10 19
 3267: 81 40 27
 83: 17 5
@@ -30,7 +40,7 @@ mod test {
     fn solve_sample_one() {
         let expected = 3749;
 
-        let actual = d07::solve_part_one(SAMPLE);
+        let actual = d07::solve_part_one(SAMPLE).unwrap();
 
         assert_eq!(expected, actual);
     }
@@ -39,8 +49,23 @@ mod test {
     fn solve_sample_two() {
         let expected = 11387;
 
-        let actual = d07::solve_part_two(SAMPLE);
+        let actual = d07::solve_part_two(SAMPLE).unwrap();
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn reject_malformed_equations() {
+        let error = d07::parse("190: 10 19\n3267 81 40 27").unwrap_err();
+        assert_eq!(
+            ParseError::new(2, 1, "3267 81 40 27", "expected `target: values`"),
+            error
+        );
+
+        let error = d07::parse("190: 10 19\n83:").unwrap_err();
+        assert_eq!(
+            ParseError::new(2, 4, "", "expected at least one value"),
+            error
+        );
+    }
 }
@@ -0,0 +1,75 @@
+use std::ops::RangeInclusive;
+
+use common::generate::{Generator, Rng};
+
+use crate::Day08;
+
+static FREQUENCIES: &str = "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Shape of a generated antenna map.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub rows: usize,
+    pub cols: usize,
+    /// Number of distinct frequencies, at most 62.
+    pub frequencies: usize,
+    /// Antennas per frequency.
+    pub antennas: RangeInclusive<u64>,
+}
+
+impl Generator for Day08 {
+    type Config = Config;
+
+    const SIZE: usize = 50;
+
+    fn config(size: usize) -> Config {
+        Config {
+            rows: size.max(1),
+            cols: size.max(1),
+            frequencies: (size * size / 60).clamp(1, FREQUENCIES.len()),
+            antennas: 3..=4,
+        }
+    }
+
+    fn generate(rng: &mut Rng, config: &Config) -> String {
+        let mut cells = vec!['.'; config.rows * config.cols];
+        let mut empty = (0..cells.len()).collect::<Vec<_>>();
+        rng.shuffle(&mut empty);
+
+        for frequency in FREQUENCIES.chars().take(config.frequencies) {
+            for _ in 0..rng.range(config.antennas.clone()) {
+                if let Some(idx) = empty.pop() {
+                    cells[idx] = frequency;
+                }
+            }
+        }
+
+        cells
+            .chunks(config.cols.max(1))
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{
+        generate::{Generator, Rng},
+        Solution,
+    };
+
+    use crate::Day08;
+
+    #[test]
+    fn generate_antenna_map() {
+        let config = Day08::config(30);
+        let input = Day08::generate(&mut Rng::new(23), &config);
+
+        assert_eq!(input, Day08::generate(&mut Rng::new(23), &config));
+        assert!(input.contains('e') && !input.contains('f'));
+
+        let grid = Day08::parse(&input).unwrap();
+        let part_one = Day08::part_one(&grid);
+        assert!(0 < part_one && part_one < Day08::part_two(&grid));
+    }
+}
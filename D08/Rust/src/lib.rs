@@ -1,60 +1,43 @@
-use std::collections::{HashMap, HashSet};
+pub mod generate;
 
-#[repr(transparent)]
-pub struct Antenna(char);
-pub struct Grid(Vec<Vec<Option<Antenna>>>);
+use std::collections::{HashMap, HashSet};
 
-impl Grid {
-    pub fn new(data: Vec<Vec<Option<Antenna>>>) -> Self {
-        Self(data)
-    }
+use common::{ParseError, Solution};
+use grid::{Grid, Position};
 
-    pub fn parse(input: &str) -> Self {
-        let data = input
-            .lines()
-            .map(|line| {
-                line.chars()
-                    .map(|c| if c == '.' { None } else { Some(Antenna(c)) })
-                    .collect()
-            })
-            .collect();
-        Self::new(data)
-    }
+pub struct Day08;
 
-    pub fn rows(&self) -> usize {
-        self.0.len()
-    }
+#[repr(transparent)]
+pub struct Antenna(char);
 
-    pub fn cols(&self) -> usize {
-        self.0[0].len()
-    }
+pub fn parse_grid(input: &str) -> Result<Grid<Option<Antenna>>, ParseError> {
+    Grid::try_parse(input, |c| match c {
+        '.' => Ok(None),
+        c if c.is_ascii_alphanumeric() => Ok(Some(Antenna(c))),
+        _ => Err("expected `.` or an antenna frequency"),
+    })
 }
 
-pub struct Map<'g>(&'g Grid, HashMap<char, Vec<(usize, usize)>>);
+pub struct Map<'g>(&'g Grid<Option<Antenna>>, HashMap<char, Vec<Position>>);
 
 impl<'g> Map<'g> {
-    pub fn scan(grid: &'g Grid) -> Self {
+    pub fn scan(grid: &'g Grid<Option<Antenna>>) -> Self {
         let mut antenna_map = HashMap::<_, Vec<_>>::new();
 
-        for (row_idx, row) in grid.0.iter().enumerate() {
-            for (col_idx, cell) in row.iter().enumerate() {
-                if let Some(Antenna(freq)) = cell {
-                    antenna_map
-                        .entry(*freq)
-                        .or_default()
-                        .push((row_idx, col_idx));
-                }
+        for (position, cell) in grid.iter() {
+            if let Some(Antenna(freq)) = cell {
+                antenna_map.entry(*freq).or_default().push(position);
             }
         }
 
         Self(grid, antenna_map)
     }
 
-    pub fn positions(&self) -> &HashMap<char, Vec<(usize, usize)>> {
+    pub fn positions(&self) -> &HashMap<char, Vec<Position>> {
         &self.1
     }
 
-    pub fn antinodes(&self) -> Vec<(usize, usize)> {
+    pub fn antinodes(&self) -> Vec<Position> {
         let mut antinodes = HashSet::new();
 
         for positions in self.1.values() {
@@ -71,14 +54,10 @@ impl<'g> Map<'g> {
                     let dr = r2 as isize - r1 as isize;
                     let dc = c2 as isize - c1 as isize;
 
-                    let antinode1 = (r1 as isize - dr, c1 as isize - dc);
-                    let antinode2 = (r2 as isize + dr, c2 as isize + dc);
+                    let antinode1 = self.0.offset(positions[i], (-dr, -dc));
+                    let antinode2 = self.0.offset(positions[j], (dr, dc));
 
-                    for (ar, ac) in [antinode1, antinode2] {
-                        if self.is_within_bounds(ar, ac) {
-                            antinodes.insert((ar as usize, ac as usize));
-                        }
-                    }
+                    antinodes.extend(antinode1.into_iter().chain(antinode2));
                 }
             }
         }
@@ -86,7 +65,7 @@ impl<'g> Map<'g> {
         antinodes.into_iter().collect()
     }
 
-    fn resonant_antinodes(&self) -> Vec<(usize, usize)> {
+    fn resonant_antinodes(&self) -> Vec<Position> {
         let mut antinodes = HashSet::new();
 
         for positions in self.1.values() {
@@ -106,30 +85,17 @@ impl<'g> Map<'g> {
                     // Add all positions along the line in both directions
                     // Starting from the first antenna, go backwards
                     let mut step = 0;
-                    loop {
-                        let ar = r1 as isize - step * dr;
-                        let ac = c1 as isize - step * dc;
-
-                        if self.is_within_bounds(ar, ac) {
-                            antinodes.insert((ar as usize, ac as usize));
-                            step += 1;
-                        } else {
-                            break;
-                        }
+                    while let Some(antinode) = self.0.offset(positions[i], (-step * dr, -step * dc))
+                    {
+                        antinodes.insert(antinode);
+                        step += 1;
                     }
 
                     // Starting from the first antenna, go forwards
                     step = 1; // Start at 1 to avoid duplicate at antenna position
-                    loop {
-                        let ar = r1 as isize + step * dr;
-                        let ac = c1 as isize + step * dc;
-
-                        if self.is_within_bounds(ar, ac) {
-                            antinodes.insert((ar as usize, ac as usize));
-                            step += 1;
-                        } else {
-                            break;
-                        }
+                    while let Some(antinode) = self.0.offset(positions[i], (step * dr, step * dc)) {
+                        antinodes.insert(antinode);
+                        step += 1;
                     }
                 }
             }
@@ -137,30 +103,47 @@ impl<'g> Map<'g> {
 
         antinodes.into_iter().collect()
     }
+}
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
 
-    fn is_within_bounds(&self, row: isize, col: isize) -> bool {
-        row >= 0 && row < self.0.rows() as isize && col >= 0 && col < self.0.cols() as isize
+    type Input = Grid<Option<Antenna>>;
+    type Answer = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_grid(input)
     }
-}
 
-pub fn solve_part_one(input: &str) -> i64 {
-    let grid = Grid::parse(input);
-    let antenna_map = Map::scan(&grid);
-    let antinodes = antenna_map.antinodes();
+    fn part_one(grid: &Self::Input) -> i64 {
+        let antenna_map = Map::scan(grid);
+        let antinodes = antenna_map.antinodes();
 
-    antinodes.len() as i64
+        antinodes.len() as i64
+    }
+
+    fn part_two(grid: &Self::Input) -> i64 {
+        let antenna_map = Map::scan(grid);
+        let antinodes = antenna_map.resonant_antinodes();
+
+        antinodes.len() as i64
+    }
 }
 
-pub fn solve_part_two(input: &str) -> i64 {
-    let grid = Grid::parse(input);
-    let antenna_map = Map::scan(&grid);
-    let antinodes = antenna_map.resonant_antinodes();
+pub fn solve_part_one(input: &str) -> Result<i64, ParseError> {
+    Ok(Day08::part_one(&parse_grid(input)?))
+}
 
-    antinodes.len() as i64
+pub fn solve_part_two(input: &str) -> Result<i64, ParseError> {
+    Ok(Day08::part_two(&parse_grid(input)?))
 }
 
 #[cfg(test)]
 mod test {
+    use common::{description::assert_examples, ParseError, Part};
+
+    use crate::Day08;
+
     static SAMPLE: &str = r"............
 ........0...
 .....0......
@@ -178,7 +161,7 @@ mod test {
     fn solve_sample_one() {
         let expected = 14;
 
-        let actual = super::solve_part_one(SAMPLE);
+        let actual = super::solve_part_one(SAMPLE).unwrap();
 
         assert_eq!(expected, actual);
     }
@@ -187,8 +170,25 @@ mod test {
     fn solve_sample_two() {
         let expected = 34;
 
-        let actual = super::solve_part_two(SAMPLE);
+        let actual = super::solve_part_two(SAMPLE).unwrap();
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn solve_description_examples() {
+        let checked = assert_examples::<Day08>(include_str!("../desc.txt"));
+
+        assert_eq!(vec![Part::One, Part::Two], checked);
+    }
+
+    #[test]
+    fn reject_unknown_cells() {
+        let error = super::solve_part_one("..0.\n.#A.").unwrap_err();
+
+        assert_eq!(
+            ParseError::new(2, 2, "#", "expected `.` or an antenna frequency"),
+            error
+        );
+    }
 }
@@ -0,0 +1,64 @@
+use common::generate::{Generator, Rng};
+
+use crate::Day09;
+
+/// Shape of a generated disk map.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// Number of digits; an even length is shortened by one so that the map
+    /// ends with a file, like the real inputs.
+    pub length: usize,
+}
+
+impl Generator for Day09 {
+    type Config = Config;
+
+    const SIZE: usize = 19_999;
+
+    fn config(size: usize) -> Config {
+        Config {
+            length: size.max(1),
+        }
+    }
+
+    fn generate(rng: &mut Rng, config: &Config) -> String {
+        let length = config.length.saturating_sub(1 - config.length % 2);
+
+        let mut disk_map = (0..length)
+            .map(|idx| {
+                let size = if idx % 2 == 0 {
+                    rng.range(1..=9)
+                } else {
+                    rng.range(0..=9)
+                };
+                char::from_digit(size as u32, 10).expect("size is a digit")
+            })
+            .collect::<String>();
+
+        disk_map.push('\n');
+        disk_map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{
+        generate::{Generator, Rng},
+        Solution,
+    };
+
+    use crate::Day09;
+
+    #[test]
+    fn generate_disk_map() {
+        let config = Day09::config(100);
+        let input = Day09::generate(&mut Rng::new(29), &config);
+
+        assert_eq!(input, Day09::generate(&mut Rng::new(29), &config));
+        assert_eq!(99, input.trim_end().len());
+
+        let disk_map = Day09::parse(&input).unwrap();
+        assert!(Day09::part_one(&disk_map) > 0);
+        assert!(Day09::part_two(&disk_map) > 0);
+    }
+}
@@ -1,7 +1,12 @@
+pub mod generate;
+
 use std::collections::BTreeMap;
 
+use common::{ParseError, Solution};
 use itertools::Itertools;
 
+pub struct Day09;
+
 #[repr(transparent)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct FileID(u64);
@@ -14,10 +19,19 @@ pub struct DiskMap {
 }
 
 impl DiskMap {
-    pub fn parse(input: &str) -> Self {
-        let (files, mut sectors): (Vec<_>, Vec<_>) = input
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let sizes = input
+            .trim_end()
             .chars()
-            .filter_map(|c| c.to_digit(10))
+            .enumerate()
+            .map(|(idx, c)| {
+                c.to_digit(10)
+                    .ok_or_else(|| ParseError::new(1, idx + 1, &c.to_string(), "expected a digit"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let (files, mut sectors): (Vec<_>, Vec<_>) = sizes
+            .into_iter()
             .enumerate()
             .partition_map(|(idx, size)| {
                 if idx % 2 == 0 {
@@ -43,25 +57,26 @@ impl DiskMap {
             })
             .collect::<Vec<_>>();
 
-        Self {
-            file_map: Self::file_map(&disk, &files),
-            sector_map: Self::sector_map(&disk, &sectors),
+        Ok(Self {
+            file_map: Self::file_map(&files, &sectors),
+            sector_map: Self::sector_map(&files, &sectors),
             disk_map: disk,
-        }
+        })
     }
 
     pub fn block_shrink(&self) -> Vec<Option<FileID>> {
         let mut data = self.disk_map.clone();
 
-        // Swap the rightmode ID with the leftmost None until all None are at the end
+        // Swap the rightmode ID with the leftmost None until all None are at the end.
+        // `right_index` is one past the last block still to be considered.
         let mut left_index = 0;
-        let mut right_index = data.len() - 1;
+        let mut right_index = data.len();
 
         loop {
-            while data[left_index].is_some() {
+            while left_index < right_index && data[left_index].is_some() {
                 left_index += 1;
             }
-            while data[right_index].is_none() {
+            while left_index < right_index && data[right_index - 1].is_none() {
                 right_index -= 1;
             }
 
@@ -69,7 +84,7 @@ impl DiskMap {
                 break;
             }
 
-            data.swap(left_index, right_index);
+            data.swap(left_index, right_index - 1);
             left_index += 1;
             right_index -= 1;
         }
@@ -77,34 +92,29 @@ impl DiskMap {
         data
     }
 
-    fn file_map(disk_map: &[Option<FileID>], files: &[u32]) -> BTreeMap<usize, u32> {
+    /// Start block and size of every non-empty file.
+    fn file_map(files: &[u32], sectors: &[u32]) -> BTreeMap<usize, u32> {
         let mut file_map = BTreeMap::new();
-        let mut file_pointer = 0;
         let mut disk_pointer = 0;
-        while disk_pointer < disk_map.len() {
-            if disk_map[disk_pointer].is_some() {
-                file_map.insert(disk_pointer, files[file_pointer]);
-                disk_pointer += files[file_pointer] as usize;
-                file_pointer += 1;
-            } else {
-                disk_pointer += 1;
+        for (file_size, sector_size) in files.iter().zip(sectors) {
+            if *file_size > 0 {
+                file_map.insert(disk_pointer, *file_size);
             }
+            disk_pointer += (*file_size + *sector_size) as usize;
         }
         file_map
     }
 
-    fn sector_map(disk_map: &[Option<FileID>], sectors: &[u32]) -> BTreeMap<usize, u32> {
+    /// Start block and size of every non-empty run of free space.
+    fn sector_map(files: &[u32], sectors: &[u32]) -> BTreeMap<usize, u32> {
         let mut sector_map = BTreeMap::new();
-        let mut sector_pointer = 0;
         let mut disk_pointer = 0;
-        while disk_pointer < disk_map.len() {
-            if disk_map[disk_pointer].is_some() {
-                disk_pointer += 1;
-            } else {
-                sector_map.insert(disk_pointer, sectors[sector_pointer]);
-                disk_pointer += sectors[sector_pointer] as usize;
-                sector_pointer += 1;
+        for (file_size, sector_size) in files.iter().zip(sectors) {
+            disk_pointer += *file_size as usize;
+            if *sector_size > 0 {
+                sector_map.insert(disk_pointer, *sector_size);
             }
+            disk_pointer += *sector_size as usize;
         }
         sector_map
     }
@@ -161,34 +171,50 @@ impl std::fmt::Display for DiskMap {
     }
 }
 
-pub fn solve_part_one(input: &str) -> u64 {
-    let disk_map = DiskMap::parse(input);
-    let disk_map = disk_map.block_shrink();
-    let checksum = checksum(&disk_map);
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = DiskMap;
+    type Answer = u64;
 
-    assert_eq!(checksum, 6471961544878); // Sanity check against accepted answer for part one
-    checksum
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        DiskMap::parse(input)
+    }
+
+    fn part_one(disk_map: &Self::Input) -> u64 {
+        let disk_map = disk_map.block_shrink();
+        checksum(&disk_map)
+    }
+
+    fn part_two(disk_map: &Self::Input) -> u64 {
+        let disk_map = disk_map.file_shrink();
+        checksum(&disk_map)
+    }
 }
 
-pub fn solve_part_two(input: &str) -> u64 {
-    let disk_map = DiskMap::parse(input);
-    let disk_map = disk_map.file_shrink();
-    let checksum = checksum(&disk_map);
+pub fn solve_part_one(input: &str) -> Result<u64, ParseError> {
+    Ok(Day09::part_one(&DiskMap::parse(input)?))
+}
 
-    assert_eq!(checksum, 6511178035564); // Sanity check against accepted answer for part two
-    checksum
+pub fn solve_part_two(input: &str) -> Result<u64, ParseError> {
+    Ok(Day09::part_two(&DiskMap::parse(input)?))
 }
 
 #[cfg(test)]
 mod test {
-    use crate::FileID;
+    use common::{
+        description::assert_examples, equivalence::assert_equivalent, ParseError, Part,
+    };
+    use proptest::prelude::*;
+
+    use crate::{Day09, FileID};
 
     static SAMPLE: &str = r"2333133121414131402";
 
     #[test]
     fn display_sample() {
         static TARGET: &str = "00...111...2...333.44.5555.6666.777.888899";
-        let disk_map = super::DiskMap::parse(SAMPLE);
+        let disk_map = super::DiskMap::parse(SAMPLE).unwrap();
         assert_eq!(TARGET, disk_map.to_string());
     }
 
@@ -239,7 +265,7 @@ mod test {
             None,
         ];
 
-        let disk_map = super::DiskMap::parse(SAMPLE);
+        let disk_map = super::DiskMap::parse(SAMPLE).unwrap();
 
         let actual = disk_map.block_shrink();
 
@@ -293,7 +319,7 @@ mod test {
             None,
         ];
 
-        let disk_map = super::DiskMap::parse(SAMPLE);
+        let disk_map = super::DiskMap::parse(SAMPLE).unwrap();
 
         let actual = disk_map.file_shrink();
 
@@ -304,7 +330,7 @@ mod test {
     fn solve_sample_one() {
         let expected = 1928;
 
-        let disk_map = super::DiskMap::parse(SAMPLE);
+        let disk_map = super::DiskMap::parse(SAMPLE).unwrap();
         let actual = disk_map
             .block_shrink()
             .iter()
@@ -324,7 +350,7 @@ mod test {
     fn solve_sample_two() {
         let expected = 2858;
 
-        let disk_map = super::DiskMap::parse(SAMPLE);
+        let disk_map = super::DiskMap::parse(SAMPLE).unwrap();
         let actual = disk_map
             .file_shrink()
             .iter()
@@ -339,4 +365,75 @@ mod test {
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn solve_sample_parts() {
+        assert_eq!(1928, super::solve_part_one(SAMPLE).unwrap());
+        assert_eq!(2858, super::solve_part_two(SAMPLE).unwrap());
+    }
+
+    #[test]
+    fn solve_description_examples() {
+        // The stored description stops before part two.
+        let checked = assert_examples::<Day09>(include_str!("../desc.txt"));
+
+        assert_eq!(vec![Part::One], checked);
+    }
+
+    #[test]
+    fn parse_rejects_non_digits() {
+        let error = super::DiskMap::parse("23x3\n").unwrap_err();
+
+        assert_eq!(ParseError::new(1, 3, "x", "expected a digit"), error);
+    }
+
+    /// Moves the last file block into the first gap, one block at a time.
+    fn naive_block_shrink(input: &str) -> Vec<Option<FileID>> {
+        let mut disk = input
+            .chars()
+            .filter_map(|c| c.to_digit(10))
+            .enumerate()
+            .flat_map(|(idx, size)| {
+                let block = (idx % 2 == 0).then_some(FileID(idx as u64 / 2));
+                std::iter::repeat_n(block, size as usize)
+            })
+            .collect::<Vec<_>>();
+
+        while let (Some(gap), Some(file)) = (
+            disk.iter().position(Option::is_none),
+            disk.iter().rposition(Option::is_some),
+        ) {
+            if gap > file {
+                break;
+            }
+            disk.swap(gap, file);
+        }
+
+        disk
+    }
+
+    /// Any disk map, including empty ones and zero-length files and gaps.
+    fn disk_map() -> impl Strategy<Value = String> {
+        prop::collection::vec(0..=9u32, 0..40)
+            .prop_map(|sizes| sizes.into_iter().map(|size| size.to_string()).collect())
+    }
+
+    #[test]
+    fn shrink_degenerate_disks() {
+        for input in ["", "5", "01", "10", "1012"] {
+            let disk_map = super::DiskMap::parse(input).unwrap();
+
+            assert_eq!(naive_block_shrink(input), disk_map.block_shrink());
+            assert_eq!(disk_map.disk_map, disk_map.file_shrink());
+        }
+    }
+
+    #[test]
+    fn block_shrink_matches_naive() {
+        assert_equivalent(
+            disk_map(),
+            |input| naive_block_shrink(input),
+            |input| super::DiskMap::parse(input).unwrap().block_shrink(),
+        );
+    }
 }
@@ -0,0 +1,84 @@
+use common::generate::{Generator, Rng};
+
+use crate::Day10;
+
+/// Shape of a generated topographic map.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub rows: usize,
+    pub cols: usize,
+    /// Number of summits; heights fall away from the nearest one, so trails
+    /// lead up to them from every side.
+    pub peaks: usize,
+    /// Percentage of positions given a random height instead, breaking some
+    /// of the trails.
+    pub noise: u64,
+}
+
+impl Generator for Day10 {
+    type Config = Config;
+
+    const SIZE: usize = 50;
+
+    fn config(size: usize) -> Config {
+        Config {
+            rows: size.max(1),
+            cols: size.max(1),
+            peaks: (size * size / 40).max(1),
+            noise: 10,
+        }
+    }
+
+    fn generate(rng: &mut Rng, config: &Config) -> String {
+        let peaks = (0..config.peaks)
+            .map(|_| (rng.index(config.rows), rng.index(config.cols)))
+            .collect::<Vec<_>>();
+
+        (0..config.rows)
+            .map(|row| {
+                let mut line = (0..config.cols)
+                    .map(|col| {
+                        let height = if rng.percent(config.noise) {
+                            rng.below(10) as usize
+                        } else {
+                            let distance = peaks
+                                .iter()
+                                .map(|&(r, c)| row.abs_diff(r) + col.abs_diff(c))
+                                .min()
+                                .unwrap_or(9);
+                            9 - distance.min(9)
+                        };
+
+                        char::from_digit(height as u32, 10).expect("height is a digit")
+                    })
+                    .collect::<String>();
+                line.push('\n');
+                line
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{
+        generate::{Generator, Rng},
+        Solution,
+    };
+
+    use crate::Day10;
+
+    #[test]
+    fn generate_height_map() {
+        let config = Day10::config(30);
+        let input = Day10::generate(&mut Rng::new(37), &config);
+
+        assert_eq!(input, Day10::generate(&mut Rng::new(37), &config));
+
+        let grid = Day10::parse(&input).unwrap();
+        assert_eq!((30, 30), (grid.rows(), grid.cols()));
+
+        let scores = Day10::part_one(&grid);
+        assert!(0 < scores && scores <= Day10::part_two(&grid));
+    }
+}
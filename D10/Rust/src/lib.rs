@@ -0,0 +1,209 @@
+pub mod generate;
+
+use std::collections::{HashMap, HashSet};
+
+use common::{ParseError, Solution};
+use grid::{Grid, Position};
+
+pub struct Day10;
+
+/// Height of every position on the map; `None` marks an impassable tile, as
+/// drawn with `.` in some of the smaller examples.
+pub type HeightMap = Grid<Option<u8>>;
+
+pub const TRAILHEAD: u8 = 0;
+
+pub const SUMMIT: u8 = 9;
+
+pub fn parse_grid(input: &str) -> Result<HeightMap, ParseError> {
+    Grid::try_parse(input, |c| match c {
+        '.' => Ok(None),
+        c => c
+            .to_digit(10)
+            .map(|height| Some(height as u8))
+            .ok_or("expected a height digit or `.`"),
+    })
+}
+
+pub fn trailheads(grid: &HeightMap) -> Vec<Position> {
+    grid.iter()
+        .filter(|(_, &height)| height == Some(TRAILHEAD))
+        .map(|(position, _)| position)
+        .collect()
+}
+
+/// Positions a hiking trail can continue to from `position`: orthogonal
+/// neighbours exactly one step higher.
+fn uphill(grid: &HeightMap, position: Position) -> impl Iterator<Item = Position> + '_ {
+    let next = grid[position].map(|height| height + 1);
+
+    grid.neighbours4(position)
+        .filter(move |&neighbour| next.is_some() && grid[neighbour] == next)
+}
+
+/// The summits reachable from `position`, memoised in `cache` so that trails
+/// sharing a stretch of path only explore it once.
+pub fn summits(
+    grid: &HeightMap,
+    position: Position,
+    cache: &mut HashMap<Position, HashSet<Position>>,
+) -> HashSet<Position> {
+    if let Some(summits) = cache.get(&position) {
+        return summits.clone();
+    }
+
+    let summits = if grid[position] == Some(SUMMIT) {
+        HashSet::from([position])
+    } else {
+        uphill(grid, position)
+            .flat_map(|next| summits(grid, next, cache))
+            .collect()
+    };
+
+    cache.insert(position, summits.clone());
+    summits
+}
+
+/// The number of distinct hiking trails from `position` to any summit,
+/// memoised in `cache`.
+pub fn rating(grid: &HeightMap, position: Position, cache: &mut Grid<Option<usize>>) -> usize {
+    if let Some(rating) = cache[position] {
+        return rating;
+    }
+
+    let rating = if grid[position] == Some(SUMMIT) {
+        1
+    } else {
+        uphill(grid, position)
+            .map(|next| rating(grid, next, cache))
+            .sum()
+    };
+
+    cache[position] = Some(rating);
+    rating
+}
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = HeightMap;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_grid(input)
+    }
+
+    /// Sum of the trailhead scores: how many summits each one reaches.
+    fn part_one(grid: &Self::Input) -> usize {
+        let mut cache = HashMap::new();
+
+        trailheads(grid)
+            .into_iter()
+            .map(|trailhead| summits(grid, trailhead, &mut cache).len())
+            .sum()
+    }
+
+    /// Sum of the trailhead ratings: how many distinct trails start at each.
+    fn part_two(grid: &Self::Input) -> usize {
+        let mut cache = Grid::filled(grid.rows(), grid.cols(), None);
+
+        trailheads(grid)
+            .into_iter()
+            .map(|trailhead| rating(grid, trailhead, &mut cache))
+            .sum()
+    }
+}
+
+pub fn solve_part_one(input: &str) -> Result<usize, ParseError> {
+    Ok(Day10::part_one(&parse_grid(input)?))
+}
+
+pub fn solve_part_two(input: &str) -> Result<usize, ParseError> {
+    Ok(Day10::part_two(&parse_grid(input)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use common::ParseError;
+
+    use super::*;
+
+    static SAMPLE: &str = r"89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+
+    static TWO_TRAILHEADS: &str = r"10..9..
+2...8..
+3...7..
+4567654
+...8..3
+...9..2
+.....01";
+
+    static FORKED: &str = r"..90..9
+...1.98
+...2..7
+6543456
+765.987
+876....
+987....";
+
+    #[test]
+    fn solve_sample_one() {
+        assert_eq!(36, solve_part_one(SAMPLE).unwrap());
+    }
+
+    #[test]
+    fn solve_sample_two() {
+        assert_eq!(81, solve_part_two(SAMPLE).unwrap());
+    }
+
+    #[test]
+    fn score_small_maps() {
+        assert_eq!(1, solve_part_one("0123\n1234\n8765\n9876").unwrap());
+        assert_eq!(3, solve_part_one(TWO_TRAILHEADS).unwrap());
+        assert_eq!(4, solve_part_one(FORKED).unwrap());
+    }
+
+    #[test]
+    fn score_each_trailhead() {
+        let grid = parse_grid(TWO_TRAILHEADS).unwrap();
+        let mut cache = HashMap::new();
+
+        let scores = trailheads(&grid)
+            .into_iter()
+            .map(|trailhead| summits(&grid, trailhead, &mut cache).len())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![1, 2], scores);
+    }
+
+    #[test]
+    fn rate_small_maps() {
+        assert_eq!(
+            3,
+            solve_part_two(".....0.\n..4321.\n..5..2.\n..6543.\n..7..4.\n..8765.\n..9....")
+                .unwrap()
+        );
+        assert_eq!(13, solve_part_two(FORKED).unwrap());
+        assert_eq!(
+            227,
+            solve_part_two("012345\n123456\n234567\n345678\n4.6789\n56789.").unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_rejects_unknown_cells() {
+        let error = parse_grid("0123\n12x4").unwrap_err();
+
+        assert_eq!(
+            ParseError::new(2, 3, "x", "expected a height digit or `.`"),
+            error
+        );
+    }
+}
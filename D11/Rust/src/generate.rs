@@ -0,0 +1,65 @@
+use std::ops::RangeInclusive;
+
+use common::generate::{Generator, Rng};
+
+use crate::Day11;
+
+/// Shape of a generated row of stones.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub stones: usize,
+    /// Digits engraved on each stone.
+    pub digits: RangeInclusive<u64>,
+}
+
+impl Generator for Day11 {
+    type Config = Config;
+
+    const SIZE: usize = 8;
+
+    fn config(size: usize) -> Config {
+        Config {
+            stones: size,
+            digits: 1..=7,
+        }
+    }
+
+    fn generate(rng: &mut Rng, config: &Config) -> String {
+        let stones = (0..config.stones)
+            .map(|_| {
+                let digits = rng.range(config.digits.clone()) as u32;
+                let smallest = if digits == 1 {
+                    0
+                } else {
+                    10u64.pow(digits - 1)
+                };
+
+                rng.range(smallest..=10u64.pow(digits) - 1).to_string()
+            })
+            .collect::<Vec<_>>();
+
+        stones.join(" ") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{
+        generate::{Generator, Rng},
+        Solution,
+    };
+
+    use crate::Day11;
+
+    #[test]
+    fn generate_stones() {
+        let config = Day11::config(20);
+        let input = Day11::generate(&mut Rng::new(31), &config);
+
+        assert_eq!(input, Day11::generate(&mut Rng::new(31), &config));
+
+        let stones = Day11::parse(&input).unwrap();
+        assert_eq!(20, stones.len());
+        assert!(Day11::part_one(&stones) >= 20);
+    }
+}
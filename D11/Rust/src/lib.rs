@@ -1,5 +1,11 @@
+pub mod generate;
+
 use std::collections::HashMap;
 
+use common::{parse::parse_number, ParseError, Solution};
+
+pub struct Day11;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Rule {
     Replace,
@@ -43,11 +49,17 @@ impl Stone {
     }
 }
 
-pub fn parse(input: &str) -> Vec<Stone> {
-    input
-        .split_whitespace()
-        .filter_map(|x| x.parse::<u64>().map(Stone).ok())
-        .collect()
+pub fn parse(input: &str) -> Result<Vec<Stone>, ParseError> {
+    let mut stones = Vec::new();
+
+    for (idx, line) in input.lines().enumerate() {
+        for token in line.split_whitespace() {
+            let stone = parse_number(line, token).map_err(|e| e.on_line(idx + 1))?;
+            stones.push(Stone(stone));
+        }
+    }
+
+    Ok(stones)
 }
 
 pub fn blink(stones: &[Stone], num_blinks: usize) -> HashMap<Stone, usize> {
@@ -80,22 +92,37 @@ pub fn blink(stones: &[Stone], num_blinks: usize) -> HashMap<Stone, usize> {
     counts
 }
 
-pub fn solve_part_one(input: &str) -> usize {
-    let numbers = parse(input);
-    let sum = blink(&numbers, 25).values().sum();
-    debug_assert_eq!(sum, 224529);
-    sum
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<Stone>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(stones: &Self::Input) -> usize {
+        blink(stones, 25).values().sum()
+    }
+
+    fn part_two(stones: &Self::Input) -> usize {
+        blink(stones, 75).values().sum()
+    }
 }
 
-pub fn solve_part_two(input: &str) -> usize {
-    let numbers = parse(input);
-    let sum = blink(&numbers, 75).values().sum();
-    debug_assert_eq!(sum, 266820198587914);
-    sum
+pub fn solve_part_one(input: &str) -> Result<usize, ParseError> {
+    Ok(Day11::part_one(&parse(input)?))
+}
+
+pub fn solve_part_two(input: &str) -> Result<usize, ParseError> {
+    Ok(Day11::part_two(&parse(input)?))
 }
 
 #[cfg(test)]
 mod tests {
+    use common::{description::assert_examples, Part};
+
     use super::*;
 
     static SAMPLE_1: &str = "0 1 10 99 999";
@@ -104,7 +131,7 @@ mod tests {
 
     #[test]
     fn test_parse() {
-        let parsed = parse(SAMPLE_1);
+        let parsed = parse(SAMPLE_1).unwrap();
         assert_eq!(
             parsed,
             vec![
@@ -119,7 +146,7 @@ mod tests {
 
     #[test]
     fn test_parse_2() {
-        let parsed = parse(SAMPLE_2);
+        let parsed = parse(SAMPLE_2).unwrap();
         assert_eq!(parsed, vec![Stone::new(125), Stone::new(17)]);
     }
 
@@ -161,7 +188,7 @@ mod tests {
 
     #[test]
     fn test_blink_once_sample_1() {
-        let stones = parse(SAMPLE_1);
+        let stones = parse(SAMPLE_1).unwrap();
         let result = blink(&stones, 1);
         assert_eq!(result.len(), 5);
         assert_eq!(
@@ -178,7 +205,7 @@ mod tests {
 
     #[test]
     fn test_blink_once_sample_2() {
-        let stones = parse(SAMPLE_2);
+        let stones = parse(SAMPLE_2).unwrap();
         let result = blink(&stones, 1);
         assert_eq!(result.len(), 3);
         assert_eq!(
@@ -193,7 +220,7 @@ mod tests {
 
     #[test]
     fn test_blink_once_sample_3() {
-        let stones = parse(SAMPLE_3);
+        let stones = parse(SAMPLE_3).unwrap();
         let result = blink(&stones, 1);
         assert_eq!(result.len(), 1);
         assert_eq!(
@@ -206,8 +233,27 @@ mod tests {
 
     #[test]
     fn test_blink_part_one() {
-        let stones = parse(SAMPLE_2);
+        let stones = parse(SAMPLE_2).unwrap();
         let result = blink(&stones, 25);
         assert_eq!(result.values().sum::<usize>(), 55312);
     }
+
+    #[test]
+    fn solve_sample_one() {
+        assert_eq!(55312, solve_part_one(SAMPLE_2).unwrap());
+    }
+
+    #[test]
+    fn solve_description_examples() {
+        // The stored description stops before part two.
+        let checked = assert_examples::<Day11>(include_str!("../desc.txt"));
+
+        assert_eq!(vec![Part::One], checked);
+    }
+
+    #[test]
+    fn test_parse_rejects_non_numbers() {
+        let error = parse("125 -17").unwrap_err();
+        assert_eq!(ParseError::new(1, 5, "-17", "expected a number"), error);
+    }
 }
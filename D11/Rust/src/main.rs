@@ -1,13 +1,21 @@
-use d11::{solve_part_one, solve_part_two};
+use std::path::Path;
+
+use common::{input::read_input, Solution};
+use d11::{solve_part_one, solve_part_two, Day11};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let data = std::fs::read_to_string("../input.txt")?;
+    let input_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../input.txt");
+    let data = read_input(
+        Day11::DAY,
+        std::env::args().nth(1).as_deref(),
+        &[input_path],
+    )?;
 
-    let a1 = solve_part_one(&data);
+    let a1 = solve_part_one(&data)?;
 
     println!("{a1}");
 
-    let a2 = solve_part_two(&data);
+    let a2 = solve_part_two(&data)?;
 
     println!("{a2}");
 
@@ -0,0 +1,90 @@
+use common::generate::{Generator, Rng};
+
+use crate::Day12;
+
+/// Shape of a generated garden map.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub rows: usize,
+    pub cols: usize,
+    /// Number of plantings; every plot grows the plant of the nearest one,
+    /// so the regions come out as irregular patches.
+    pub plantings: usize,
+    /// Number of distinct plants, from `A` onwards.
+    pub plants: usize,
+    /// Percentage of plots given a random plant instead, leaving small
+    /// regions inside the larger ones.
+    pub noise: u64,
+}
+
+impl Generator for Day12 {
+    type Config = Config;
+
+    const SIZE: usize = 140;
+
+    fn config(size: usize) -> Config {
+        Config {
+            rows: size.max(1),
+            cols: size.max(1),
+            plantings: (size * size / 25).max(1),
+            plants: 26,
+            noise: 2,
+        }
+    }
+
+    fn generate(rng: &mut Rng, config: &Config) -> String {
+        let plant = |rng: &mut Rng| (b'A' + rng.index(config.plants.min(26)) as u8) as char;
+
+        let plantings = (0..config.plantings)
+            .map(|_| {
+                let position = (rng.index(config.rows), rng.index(config.cols));
+                (position, plant(rng))
+            })
+            .collect::<Vec<_>>();
+
+        (0..config.rows)
+            .map(|row| {
+                let mut line = (0..config.cols)
+                    .map(|col| {
+                        if rng.percent(config.noise) {
+                            return plant(rng);
+                        }
+
+                        plantings
+                            .iter()
+                            .min_by_key(|((r, c), _)| row.abs_diff(*r) + col.abs_diff(*c))
+                            .map_or('A', |&(_, plant)| plant)
+                    })
+                    .collect::<String>();
+                line.push('\n');
+                line
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{
+        generate::{Generator, Rng},
+        Solution,
+    };
+
+    use crate::{regions, Day12};
+
+    #[test]
+    fn generate_garden() {
+        let config = Day12::config(40);
+        let input = Day12::generate(&mut Rng::new(12), &config);
+
+        assert_eq!(input, Day12::generate(&mut Rng::new(12), &config));
+
+        let garden = Day12::parse(&input).unwrap();
+        assert_eq!((40, 40), (garden.rows(), garden.cols()));
+
+        let regions = regions(&garden);
+        assert!(regions.len() > 1);
+        assert_eq!(40 * 40, regions.iter().map(|r| r.area()).sum::<usize>());
+        assert!(Day12::part_two(&garden) <= Day12::part_one(&garden));
+    }
+}
@@ -0,0 +1,253 @@
+pub mod generate;
+
+use std::collections::HashSet;
+
+use common::{ParseError, Solution};
+use grid::{step, Direction, Grid, Position, Rotation};
+
+pub struct Day12;
+
+/// The plant growing on every garden plot.
+pub type Garden = Grid<char>;
+
+/// A fence along one `side` of the plot at `position`, separating it from a
+/// different plant or from the edge of the map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fence {
+    pub position: Position,
+    pub side: Direction,
+}
+
+/// Orthogonally connected plots growing the same plant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub plant: char,
+    /// Plots in the order the flood fill reached them, starting with the
+    /// first in row-major order.
+    pub plots: Vec<Position>,
+    pub fences: Vec<Fence>,
+}
+
+impl Region {
+    pub fn area(&self) -> usize {
+        self.plots.len()
+    }
+
+    pub fn perimeter(&self) -> usize {
+        self.fences.len()
+    }
+
+    /// Number of straight sections of fence, which is the number of corners
+    /// around the region. Each pair of neighbouring sides of a plot makes an
+    /// outside corner when neither plot on those sides is in the region, and
+    /// an inside corner when both are but the diagonal one between them is
+    /// not.
+    pub fn sides(&self) -> usize {
+        let plots = self.plots.iter().copied().collect::<HashSet<_>>();
+        let inside = |plot, offset| step(plot, offset).is_some_and(|plot| plots.contains(&plot));
+        let corner = |plot, side: Direction| {
+            let next = side.rotate(Rotation::Clockwise);
+            let ((dr, dc), (nr, nc)) = (side.offset(), next.offset());
+
+            match (inside(plot, (dr, dc)), inside(plot, (nr, nc))) {
+                (false, false) => true,
+                (true, true) => !inside(plot, (dr + nr, dc + nc)),
+                _ => false,
+            }
+        };
+
+        self.plots
+            .iter()
+            .flat_map(|&plot| {
+                Direction::ALL
+                    .into_iter()
+                    .filter(move |&side| corner(plot, side))
+            })
+            .count()
+    }
+
+    pub fn price(&self) -> usize {
+        self.area() * self.perimeter()
+    }
+
+    /// Price with the bulk discount, which charges by side instead of by
+    /// length of fence.
+    pub fn discounted_price(&self) -> usize {
+        self.area() * self.sides()
+    }
+}
+
+pub fn parse_grid(input: &str) -> Result<Garden, ParseError> {
+    Grid::try_parse(input, |c| {
+        if c.is_ascii_uppercase() {
+            Ok(c)
+        } else {
+            Err("expected a plant letter")
+        }
+    })
+}
+
+/// Flood fills `garden` into its regions, ordered by their first plot in
+/// row-major order.
+pub fn regions(garden: &Garden) -> Vec<Region> {
+    let mut seen = Grid::filled(garden.rows(), garden.cols(), false);
+    let mut regions = Vec::new();
+
+    for start in garden.positions() {
+        if seen[start] {
+            continue;
+        }
+
+        let plant = garden[start];
+        let mut region = Region {
+            plant,
+            plots: vec![start],
+            fences: Vec::new(),
+        };
+        seen[start] = true;
+
+        let mut next = 0;
+        while let Some(&position) = region.plots.get(next) {
+            next += 1;
+
+            for side in Direction::ALL {
+                match garden.offset(position, side.offset()) {
+                    Some(neighbour) if garden[neighbour] == plant => {
+                        if !seen[neighbour] {
+                            seen[neighbour] = true;
+                            region.plots.push(neighbour);
+                        }
+                    }
+                    _ => region.fences.push(Fence { position, side }),
+                }
+            }
+        }
+
+        regions.push(region);
+    }
+
+    regions
+}
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Garden;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_grid(input)
+    }
+
+    /// Total price of fencing every region by area and perimeter.
+    fn part_one(garden: &Self::Input) -> usize {
+        regions(garden).iter().map(Region::price).sum()
+    }
+
+    /// Total price of fencing every region by area and number of sides.
+    fn part_two(garden: &Self::Input) -> usize {
+        regions(garden).iter().map(Region::discounted_price).sum()
+    }
+}
+
+pub fn solve_part_one(input: &str) -> Result<usize, ParseError> {
+    Ok(Day12::part_one(&parse_grid(input)?))
+}
+
+pub fn solve_part_two(input: &str) -> Result<usize, ParseError> {
+    Ok(Day12::part_two(&parse_grid(input)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use common::ParseError;
+
+    use super::*;
+
+    static SAMPLE: &str = r"RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE";
+
+    static SMALL: &str = r"AAAA
+BBCD
+BBCC
+EEEC";
+
+    static NESTED: &str = r"OOOOO
+OXOXO
+OOOOO
+OXOXO
+OOOOO";
+
+    #[test]
+    fn solve_sample_one() {
+        assert_eq!(1930, solve_part_one(SAMPLE).unwrap());
+    }
+
+    #[test]
+    fn solve_sample_two() {
+        assert_eq!(1206, solve_part_two(SAMPLE).unwrap());
+    }
+
+    #[test]
+    fn price_small_maps() {
+        assert_eq!(140, solve_part_one(SMALL).unwrap());
+        assert_eq!(772, solve_part_one(NESTED).unwrap());
+
+        assert_eq!(80, solve_part_two(SMALL).unwrap());
+        assert_eq!(436, solve_part_two(NESTED).unwrap());
+        assert_eq!(
+            236,
+            solve_part_two("EEEEE\nEXXXX\nEEEEE\nEXXXX\nEEEEE").unwrap()
+        );
+        assert_eq!(
+            368,
+            solve_part_two("AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA").unwrap()
+        );
+    }
+
+    #[test]
+    fn measure_each_region() {
+        let regions = regions(&parse_grid(SMALL).unwrap());
+
+        let measured = regions
+            .iter()
+            .map(|r| (r.plant, r.area(), r.perimeter(), r.sides()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                ('A', 4, 10, 4),
+                ('B', 4, 8, 4),
+                ('C', 4, 10, 8),
+                ('D', 1, 4, 4),
+                ('E', 3, 8, 4),
+            ],
+            measured
+        );
+        assert_eq!(vec![(1, 2), (2, 2), (2, 3), (3, 3)], regions[2].plots);
+    }
+
+    #[test]
+    fn separate_regions_of_the_same_plant() {
+        let regions = regions(&parse_grid(NESTED).unwrap());
+
+        assert_eq!(5, regions.len());
+        assert_eq!(4, regions.iter().filter(|r| r.plant == 'X').count());
+        assert_eq!(36, regions[0].perimeter());
+    }
+
+    #[test]
+    fn parse_rejects_unknown_cells() {
+        let error = parse_grid("AB\nc D").unwrap_err();
+
+        assert_eq!(ParseError::new(2, 1, "c", "expected a plant letter"), error);
+    }
+}
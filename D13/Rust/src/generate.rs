@@ -0,0 +1,86 @@
+use std::ops::RangeInclusive;
+
+use common::generate::{Generator, Rng};
+
+use crate::{Day13, PRESS_LIMIT};
+
+/// Shape of a generated list of claw machines.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub machines: usize,
+    /// How far a button moves the claw along each axis.
+    pub reach: RangeInclusive<u64>,
+    /// Percentage of machines whose prize is placed by pressing the buttons,
+    /// so that it can be won in part one. The rest are placed at random.
+    pub winnable: u64,
+}
+
+impl Generator for Day13 {
+    type Config = Config;
+
+    const SIZE: usize = 320;
+
+    fn config(size: usize) -> Config {
+        Config {
+            machines: size,
+            reach: 10..=99,
+            winnable: 40,
+        }
+    }
+
+    fn generate(rng: &mut Rng, config: &Config) -> String {
+        let machines = (0..config.machines)
+            .map(|_| {
+                let a = (
+                    rng.range(config.reach.clone()),
+                    rng.range(config.reach.clone()),
+                );
+                let b = (
+                    rng.range(config.reach.clone()),
+                    rng.range(config.reach.clone()),
+                );
+
+                let prize = if rng.percent(config.winnable) {
+                    let (pa, pb) = (rng.below(PRESS_LIMIT + 1), rng.below(PRESS_LIMIT + 1));
+                    (a.0 * pa + b.0 * pb, a.1 * pa + b.1 * pb)
+                } else {
+                    let far = config.reach.end() * PRESS_LIMIT;
+                    (rng.below(far), rng.below(far))
+                };
+
+                format!(
+                    "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                    a.0, a.1, b.0, b.1, prize.0, prize.1
+                )
+            })
+            .collect::<Vec<_>>();
+
+        machines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{
+        generate::{Generator, Rng},
+        Solution,
+    };
+
+    use crate::{presses, Day13, PRESS_LIMIT};
+
+    #[test]
+    fn generate_machines() {
+        let config = Day13::config(50);
+        let input = Day13::generate(&mut Rng::new(13), &config);
+
+        assert_eq!(input, Day13::generate(&mut Rng::new(13), &config));
+
+        let machines = Day13::parse(&input).unwrap();
+        assert_eq!(50, machines.len());
+
+        let won = presses(&machines, 0, Some(PRESS_LIMIT));
+        assert!(won.iter().any(Option::is_some));
+        assert!(Day13::part_one(&machines) > 0);
+        Day13::part_two(&machines);
+    }
+}
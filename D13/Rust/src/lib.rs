@@ -0,0 +1,442 @@
+pub mod generate;
+
+use common::{parse::parse_number, ParseError, Solution};
+
+pub struct Day13;
+
+/// Tokens it costs to press button A.
+pub const A_COST: u64 = 3;
+
+/// Tokens it costs to press button B.
+pub const B_COST: u64 = 1;
+
+/// Most times either button may be pressed in part one.
+pub const PRESS_LIMIT: u64 = 100;
+
+/// How much further away every prize turns out to be in part two, along both
+/// axes.
+pub const PRIZE_OFFSET: u64 = 10_000_000_000_000;
+
+/// A claw machine: how far each button moves the claw along `(x, y)` and
+/// where the prize is.
+///
+/// Coordinates are kept to `u32` so that every product the solver forms,
+/// even with a `u64` offset on the prize, fits comfortably in an `i128`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Machine {
+    pub a: (u32, u32),
+    pub b: (u32, u32),
+    pub prize: (u32, u32),
+}
+
+/// How many times to press each button to win a prize, and what it costs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Presses {
+    pub a: u64,
+    pub b: u64,
+    pub tokens: u64,
+}
+
+impl Presses {
+    fn new(a: i128, b: i128) -> Option<Self> {
+        let tokens = a * i128::from(A_COST) + b * i128::from(B_COST);
+
+        Some(Self {
+            a: a.try_into().ok()?,
+            b: b.try_into().ok()?,
+            tokens: tokens.try_into().ok()?,
+        })
+    }
+}
+
+impl Machine {
+    /// The cheapest presses that win the prize once it is moved `offset`
+    /// further along both axes, pressing neither button more than `limit`
+    /// times. `None` if the prize cannot be reached, or only with more
+    /// presses or tokens than fit in a `u64`.
+    pub fn solve(&self, offset: u64, limit: Option<u64>) -> Option<Presses> {
+        let (ax, ay) = (i128::from(self.a.0), i128::from(self.a.1));
+        let (bx, by) = (i128::from(self.b.0), i128::from(self.b.1));
+        let px = i128::from(self.prize.0) + i128::from(offset);
+        let py = i128::from(self.prize.1) + i128::from(offset);
+        let limit = limit.map_or(i128::MAX, i128::from);
+
+        let determinant = ax * by - ay * bx;
+
+        let (a, b) = if determinant != 0 {
+            // Cramer's rule: the buttons are independent, so there is exactly
+            // one way to reach the prize, if it takes whole presses.
+            let a = px * by - py * bx;
+            let b = ax * py - ay * px;
+
+            if a % determinant != 0 || b % determinant != 0 {
+                return None;
+            }
+            (a / determinant, b / determinant)
+        } else {
+            // The buttons move the claw along the same line, so solve along
+            // one axis and check that the other one lands on the prize too.
+            let (a, b) = if ax != 0 {
+                cheapest_along_line(ax, bx, px, limit)?
+            } else {
+                cheapest_along_line(ay, by, py, limit)?
+            };
+
+            if a * ax + b * bx != px || a * ay + b * by != py {
+                return None;
+            }
+            (a, b)
+        };
+
+        if a < 0 || b < 0 || a > limit || b > limit {
+            return None;
+        }
+
+        Presses::new(a, b)
+    }
+}
+
+/// The cheapest non-negative `(a, b)` with `a * p + b * q == r`, where `p` and
+/// `q` are positive, and neither more than `limit`.
+///
+/// Every solution is `(a0 + k * q / g, b0 - k * p / g)` for one particular
+/// solution `(a0, b0)` and `g = gcd(p, q)`, and the cost changes by the same
+/// amount with every step of `k`, so the cheapest solution sits at one end of
+/// the range of `k` that keeps both counts in bounds.
+fn cheapest_along_line(p: i128, q: i128, r: i128, limit: i128) -> Option<(i128, i128)> {
+    let (g, x, _) = extended_gcd(p, q);
+    if r % g != 0 {
+        return None;
+    }
+
+    let (step_a, step_b) = (q / g, p / g);
+
+    // The fewest presses of A that leave a whole number of B presses. Both
+    // factors are reduced below `step_a`, at most a button's `u32` reach,
+    // before multiplying.
+    let a0 = x.rem_euclid(step_a) * (r / g).rem_euclid(step_a) % step_a;
+    let b0 = (r - a0 * p) / q;
+    if b0 < 0 {
+        return None;
+    }
+
+    // Bounds on `k` from `b <= limit`, `a <= limit` and `b >= 0`.
+    let low = if b0 > limit {
+        (b0 - limit + step_b - 1) / step_b
+    } else {
+        0
+    };
+    let high = if a0 > limit {
+        return None;
+    } else {
+        ((limit - a0) / step_a).min(b0 / step_b)
+    };
+    if low > high {
+        return None;
+    }
+
+    let change = step_a * i128::from(A_COST) - step_b * i128::from(B_COST);
+    let k = if change < 0 { high } else { low };
+
+    Some((a0 + k * step_a, b0 - k * step_b))
+}
+
+/// `(gcd(a, b), x, y)` with `a * x + b * y == gcd(a, b)`.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - (a / b) * y)
+    }
+}
+
+/// Parses `X<sign>x, Y<sign>y`, the part of `line` after its label.
+fn parse_coordinates(line: &str, rest: &str, sign: char) -> Result<(u32, u32), ParseError> {
+    let expected = if sign == '+' {
+        "expected `X+x, Y+y`"
+    } else {
+        "expected `X=x, Y=y`"
+    };
+
+    let (x, y) = rest
+        .split_once(',')
+        .ok_or_else(|| ParseError::token(line, rest, expected))?;
+
+    let axis = |token: &str, name: char| {
+        let token = token.trim();
+        token
+            .strip_prefix(name)
+            .and_then(|token| token.strip_prefix(sign))
+            .ok_or_else(|| ParseError::token(line, token, expected))
+            .and_then(|value| parse_number(line, value))
+    };
+
+    Ok((axis(x, 'X')?, axis(y, 'Y')?))
+}
+
+/// Parses one of the three lines describing a machine, which must start with
+/// `label`.
+fn parse_line(line: &str, label: &str, sign: char) -> Result<(u32, u32), ParseError> {
+    let rest = line.strip_prefix(label).ok_or_else(|| {
+        ParseError::token(line, line, &format!("expected a line starting `{label}`"))
+    })?;
+
+    parse_coordinates(line, rest, sign)
+}
+
+pub fn parse(input: &str) -> Result<Vec<Machine>, ParseError> {
+    const LINES: [(&str, char); 3] = [("Button A:", '+'), ("Button B:", '+'), ("Prize:", '=')];
+
+    let mut machines = Vec::new();
+    let mut pending = Vec::with_capacity(LINES.len());
+    let mut last = 0;
+
+    for (idx, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        last = idx + 1;
+
+        let (label, sign) = LINES[pending.len()];
+        let coordinates = parse_line(line, label, sign).map_err(|e| e.on_line(idx + 1))?;
+
+        if pending.len() < 2 && coordinates == (0, 0) {
+            return Err(
+                ParseError::token(line, line, "button does not move the claw").on_line(idx + 1),
+            );
+        }
+
+        pending.push(coordinates);
+        if let [a, b, prize] = pending[..] {
+            machines.push(Machine { a, b, prize });
+            pending.clear();
+        }
+    }
+
+    if !pending.is_empty() {
+        let (label, _) = LINES[pending.len()];
+        return Err(ParseError::new(
+            last + 1,
+            1,
+            "",
+            &format!("expected a line starting `{label}`"),
+        ));
+    }
+
+    Ok(machines)
+}
+
+/// The cheapest way to win each machine's prize, if there is one.
+pub fn presses(machines: &[Machine], offset: u64, limit: Option<u64>) -> Vec<Option<Presses>> {
+    machines
+        .iter()
+        .map(|machine| machine.solve(offset, limit))
+        .collect()
+}
+
+fn fewest_tokens(machines: &[Machine], offset: u64, limit: Option<u64>) -> u64 {
+    presses(machines, offset, limit)
+        .into_iter()
+        .flatten()
+        .map(|presses| presses.tokens)
+        .sum()
+}
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<Machine>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    /// Fewest tokens to win every winnable prize with at most 100 presses of
+    /// each button.
+    fn part_one(machines: &Self::Input) -> u64 {
+        fewest_tokens(machines, 0, Some(PRESS_LIMIT))
+    }
+
+    /// Fewest tokens to win every winnable prize once the prizes are moved
+    /// out by `PRIZE_OFFSET`, with no limit on presses.
+    fn part_two(machines: &Self::Input) -> u64 {
+        fewest_tokens(machines, PRIZE_OFFSET, None)
+    }
+}
+
+pub fn solve_part_one(input: &str) -> Result<u64, ParseError> {
+    Ok(Day13::part_one(&parse(input)?))
+}
+
+pub fn solve_part_two(input: &str) -> Result<u64, ParseError> {
+    Ok(Day13::part_two(&parse(input)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use common::ParseError;
+
+    use super::*;
+
+    static SAMPLE: &str = r"Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279";
+
+    fn machine(a: (u32, u32), b: (u32, u32), prize: (u32, u32)) -> Machine {
+        Machine { a, b, prize }
+    }
+
+    #[test]
+    fn solve_sample_one() {
+        assert_eq!(480, solve_part_one(SAMPLE).unwrap());
+    }
+
+    #[test]
+    fn solve_sample_two() {
+        assert_eq!(875318608908, solve_part_two(SAMPLE).unwrap());
+    }
+
+    #[test]
+    fn presses_per_machine() {
+        let machines = parse(SAMPLE).unwrap();
+
+        assert_eq!(
+            vec![
+                Some(Presses {
+                    a: 80,
+                    b: 40,
+                    tokens: 280
+                }),
+                None,
+                Some(Presses {
+                    a: 38,
+                    b: 86,
+                    tokens: 200
+                }),
+                None,
+            ],
+            presses(&machines, 0, Some(PRESS_LIMIT))
+        );
+
+        let far = presses(&machines, PRIZE_OFFSET, None);
+        assert_eq!(
+            vec![false, true, false, true],
+            far.iter().map(Option::is_some).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn limit_presses() {
+        let far = machine((1, 0), (0, 1), (150, 20));
+
+        assert_eq!(None, far.solve(0, Some(PRESS_LIMIT)));
+        assert_eq!(
+            Some(Presses {
+                a: 150,
+                b: 20,
+                tokens: 470
+            }),
+            far.solve(0, None)
+        );
+    }
+
+    #[test]
+    fn solve_parallel_buttons() {
+        // Pressing B is cheaper per step along the line.
+        assert_eq!(
+            Some(Presses {
+                a: 0,
+                b: 5,
+                tokens: 5
+            }),
+            machine((1, 1), (2, 2), (10, 10)).solve(0, None)
+        );
+        // Pressing A is cheaper per step along the line.
+        assert_eq!(
+            Some(Presses {
+                a: 2,
+                b: 0,
+                tokens: 6
+            }),
+            machine((4, 4), (1, 1), (8, 8)).solve(0, None)
+        );
+        // A alone would take 3 presses, over the limit of 2.
+        assert_eq!(
+            Some(Presses {
+                a: 2,
+                b: 2,
+                tokens: 8
+            }),
+            machine((2, 6), (1, 3), (6, 18)).solve(0, Some(2))
+        );
+        assert_eq!(None, machine((2, 2), (4, 4), (5, 5)).solve(0, None));
+        assert_eq!(None, machine((1, 2), (2, 4), (3, 7)).solve(0, None));
+    }
+
+    #[test]
+    fn solve_without_overflow() {
+        let max = u32::MAX;
+        let corner = machine((max, 0), (0, max), (max, max));
+
+        assert_eq!(
+            Some(Presses {
+                a: 1,
+                b: 1,
+                tokens: 4
+            }),
+            corner.solve(0, None)
+        );
+
+        let presses = 1 + (1 << 31);
+        assert_eq!(
+            Some(Presses {
+                a: presses,
+                b: presses,
+                tokens: 4 * presses
+            }),
+            corner.solve(u64::from(max) << 31, None)
+        );
+
+        // Reachable, but only with more tokens than a `u64` holds.
+        assert_eq!(None, machine((1, 0), (0, 1), (0, 0)).solve(u64::MAX, None));
+    }
+
+    #[test]
+    fn parse_rejects_malformed_machines() {
+        assert_eq!(
+            ParseError::new(2, 17, "Y-67", "expected `X+x, Y+y`"),
+            parse("Button A: X+94, Y+34\nButton B: X+22, Y-67").unwrap_err()
+        );
+        assert_eq!(
+            ParseError::new(
+                3,
+                1,
+                "Button A: X+1, Y+2",
+                "expected a line starting `Prize:`"
+            ),
+            parse("Button A: X+94, Y+34\nButton B: X+22, Y+67\nButton A: X+1, Y+2").unwrap_err()
+        );
+        assert_eq!(
+            ParseError::new(3, 1, "", "expected a line starting `Prize:`"),
+            parse("Button A: X+94, Y+34\nButton B: X+22, Y+67\n").unwrap_err()
+        );
+        assert_eq!(
+            ParseError::new(1, 13, "99999999999", "expected a number"),
+            parse("Button A: X+99999999999, Y+34").unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use common::generate::{Generator, Rng};
+
+use crate::{Day14, Space};
+
+/// Shape of a generated list of robots.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub robots: usize,
+    /// How many of the robots line up into a tree at `seconds`; the rest are
+    /// scattered at random.
+    pub picture: usize,
+    /// Second at which the tree forms, below `101 * 103`.
+    pub seconds: u64,
+}
+
+impl Generator for Day14 {
+    type Config = Config;
+
+    const SIZE: usize = 500;
+
+    fn config(size: usize) -> Config {
+        Config {
+            robots: size,
+            picture: size * 3 / 5,
+            seconds: 6_587,
+        }
+    }
+
+    fn generate(rng: &mut Rng, config: &Config) -> String {
+        let Space { width, height } = Space::BATHROOM;
+
+        // Rows of a tree, one tile wider on each side per row, filled from
+        // the top until there are enough robots.
+        let rows = (config.picture as f64).sqrt().ceil() as i64;
+        let top = (
+            rng.range(rows as u64..=(width - rows - 1) as u64) as i64,
+            rng.below((height - rows) as u64) as i64,
+        );
+        let tree = (0..rows)
+            .flat_map(|row| (-row..=row).map(move |col| (top.0 + col, top.1 + row)))
+            .take(config.picture);
+
+        let scattered = (config.picture..config.robots).map(|_| {
+            (
+                rng.below(width as u64) as i64,
+                rng.below(height as u64) as i64,
+            )
+        });
+        let targets = tree.chain(scattered).collect::<Vec<_>>();
+
+        let seconds = config.seconds as i64;
+        targets
+            .into_iter()
+            .map(|(x, y)| {
+                let velocity = (
+                    rng.range(0..=200) as i64 - 100,
+                    rng.range(0..=200) as i64 - 100,
+                );
+                let position = (
+                    (x - velocity.0 * seconds).rem_euclid(width),
+                    (y - velocity.1 * seconds).rem_euclid(height),
+                );
+
+                format!(
+                    "p={},{} v={},{}\n",
+                    position.0, position.1, velocity.0, velocity.1
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{
+        generate::{Generator, Rng},
+        Solution,
+    };
+
+    use crate::{Day14, Frame, Space};
+
+    #[test]
+    fn generate_robots() {
+        let config = Day14::config(200);
+        let input = Day14::generate(&mut Rng::new(14), &config);
+
+        assert_eq!(input, Day14::generate(&mut Rng::new(14), &config));
+
+        let robots = Day14::parse(&input).unwrap();
+        assert_eq!(200, robots.len());
+        assert_eq!(config.seconds, Day14::part_two(&robots));
+
+        let frame = Frame::after(&robots, Space::BATHROOM, config.seconds).to_string();
+        assert!(frame.contains("1111111"));
+    }
+}
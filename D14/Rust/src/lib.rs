@@ -0,0 +1,362 @@
+pub mod generate;
+
+use common::{parse::parse_number, ParseError, Solution};
+
+pub struct Day14;
+
+/// Seconds the robots are left to move in part one.
+pub const SECONDS: u64 = 100;
+
+/// The tiles robots move across, wrapping around at the edges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Space {
+    pub width: i64,
+    pub height: i64,
+}
+
+impl Space {
+    /// The space outside the bathroom, as opposed to the smaller one in the
+    /// examples.
+    pub const BATHROOM: Space = Space {
+        width: 101,
+        height: 103,
+    };
+}
+
+/// A robot's starting `(x, y)` tile and how far it moves each second.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Robot {
+    pub position: (i64, i64),
+    pub velocity: (i64, i64),
+}
+
+impl Robot {
+    /// Where the robot is after `seconds`, worked out directly rather than
+    /// step by step. Every factor is reduced by the size of the space first,
+    /// so the product cannot overflow however long the robot runs.
+    pub fn after(&self, space: Space, seconds: u64) -> (i64, i64) {
+        let axis = |position: i64, velocity: i64, size: i64| {
+            let seconds = (seconds % size as u64) as i64;
+            (position.rem_euclid(size) + velocity.rem_euclid(size) * seconds).rem_euclid(size)
+        };
+
+        (
+            axis(self.position.0, self.velocity.0, space.width),
+            axis(self.position.1, self.velocity.1, space.height),
+        )
+    }
+}
+
+/// Where every robot is at one moment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub space: Space,
+    pub positions: Vec<(i64, i64)>,
+}
+
+impl Frame {
+    pub fn after(robots: &[Robot], space: Space, seconds: u64) -> Self {
+        Self {
+            space,
+            positions: robots.iter().map(|r| r.after(space, seconds)).collect(),
+        }
+    }
+
+    /// Number of robots in each quadrant, ignoring those on the middle row or
+    /// column, in reading order.
+    pub fn quadrants(&self) -> [usize; 4] {
+        let mut quadrants = [0; 4];
+
+        for &(x, y) in &self.positions {
+            let half = |value: i64, size: i64| match (2 * value + 1).cmp(&size) {
+                std::cmp::Ordering::Less => Some(0),
+                std::cmp::Ordering::Equal => None,
+                std::cmp::Ordering::Greater => Some(1),
+            };
+
+            if let (Some(column), Some(row)) =
+                (half(x, self.space.width), half(y, self.space.height))
+            {
+                quadrants[row * 2 + column] += 1;
+            }
+        }
+
+        quadrants
+    }
+
+    pub fn safety_factor(&self) -> usize {
+        self.quadrants().iter().product()
+    }
+}
+
+impl std::fmt::Display for Frame {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut counts = vec![0u32; (self.space.width * self.space.height) as usize];
+        for &(x, y) in &self.positions {
+            counts[(y * self.space.width + x) as usize] += 1;
+        }
+
+        let mut result = String::new();
+
+        for (idx, row) in counts.chunks(self.space.width as usize).enumerate() {
+            if idx > 0 {
+                result.push('\n');
+            }
+            for &count in row {
+                result.push(match count {
+                    0 => '.',
+                    1..=9 => char::from_digit(count, 10).expect("count is a digit"),
+                    _ => '#',
+                });
+            }
+        }
+
+        write!(f, "{}", result)
+    }
+}
+
+/// How spread out `values` are: their variance, scaled by the square of their
+/// count so that it stays a whole number.
+pub fn spread(values: impl Iterator<Item = i64>) -> i64 {
+    let (count, sum, squares) = values.fold((0, 0, 0), |(count, sum, squares), value| {
+        (count + 1, sum + value, squares + value * value)
+    });
+
+    count * squares - sum * sum
+}
+
+/// The first second, within `size`, at which one axis of the robots'
+/// positions is least spread out. Each axis repeats every `size` seconds.
+fn tightest(robots: &[Robot], size: i64, axis: impl Fn(&Robot, u64) -> i64) -> u64 {
+    (0..size as u64)
+        .min_by_key(|&seconds| spread(robots.iter().map(|robot| axis(robot, seconds))))
+        .unwrap_or(0)
+}
+
+/// The first second at which the robots huddle together into a picture.
+///
+/// A picture packs most robots into a small area, so it is taken to be the
+/// moment at which their positions vary least. The two axes wrap
+/// independently, every `width` and every `height` seconds, so each is
+/// searched over one period on its own and the two seconds are combined
+/// with the Chinese remainder theorem. `None` if the sides of the space
+/// share a factor, as the combined second is then not unique.
+pub fn picture(robots: &[Robot], space: Space) -> Option<u64> {
+    let x = tightest(robots, space.width, |robot, s| robot.after(space, s).0);
+    let y = tightest(robots, space.height, |robot, s| robot.after(space, s).1);
+
+    let (width, height) = (space.width as u64, space.height as u64);
+    let inverse = modular_inverse(width % height, height)?;
+
+    // Start from `x` and add whole periods of `width` until `y` lines up.
+    let periods = (y + height - x % height) % height * inverse % height;
+    Some(x + periods * width)
+}
+
+/// The inverse of `value` modulo `modulus`, if they are coprime.
+fn modular_inverse(value: u64, modulus: u64) -> Option<u64> {
+    let (mut old_r, mut r) = (value as i64, modulus as i64);
+    let (mut old_s, mut s) = (1i64, 0i64);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_s, s) = (s, old_s - quotient * s);
+    }
+
+    (old_r == 1).then(|| old_s.rem_euclid(modulus as i64) as u64)
+}
+
+/// Parses a `p=x,y v=dx,dy` robot.
+pub fn parse_line(line: &str) -> Result<Robot, ParseError> {
+    let pair = |token: &str, label: &str| {
+        let expected = format!("expected `{label}x,y`");
+        let (x, y) = token
+            .strip_prefix(label)
+            .and_then(|pair| pair.split_once(','))
+            .ok_or_else(|| ParseError::token(line, token, &expected))?;
+
+        Ok::<_, ParseError>((parse_number(line, x)?, parse_number(line, y)?))
+    };
+
+    let (position, velocity) = line
+        .split_once(' ')
+        .ok_or_else(|| ParseError::token(line, line, "expected `p=x,y v=dx,dy`"))?;
+
+    Ok(Robot {
+        position: pair(position, "p=")?,
+        velocity: pair(velocity.trim_start(), "v=")?,
+    })
+}
+
+pub fn parse(input: &str) -> Result<Vec<Robot>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| parse_line(line).map_err(|e| e.on_line(idx + 1)))
+        .collect()
+}
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Vec<Robot>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    /// Safety factor of the bathroom after `SECONDS`.
+    fn part_one(robots: &Self::Input) -> u64 {
+        Frame::after(robots, Space::BATHROOM, SECONDS).safety_factor() as u64
+    }
+
+    /// First second at which the robots form a picture.
+    fn part_two(robots: &Self::Input) -> u64 {
+        picture(robots, Space::BATHROOM).expect("the bathroom's sides are coprime")
+    }
+}
+
+pub fn solve_part_one(input: &str) -> Result<u64, ParseError> {
+    Ok(Day14::part_one(&parse(input)?))
+}
+
+pub fn solve_part_two(input: &str) -> Result<u64, ParseError> {
+    Ok(Day14::part_two(&parse(input)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use common::ParseError;
+
+    use super::*;
+
+    static SAMPLE: &str = r"p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+
+    const EXAMPLE: Space = Space {
+        width: 11,
+        height: 7,
+    };
+
+    #[test]
+    fn solve_sample_one() {
+        let robots = parse(SAMPLE).unwrap();
+
+        assert_eq!(12, Frame::after(&robots, EXAMPLE, SECONDS).safety_factor());
+    }
+
+    #[test]
+    fn display_sample() {
+        let robots = parse(SAMPLE).unwrap();
+
+        assert_eq!(
+            "1.12.......\n\
+             ...........\n\
+             ...........\n\
+             ......11.11\n\
+             1.1........\n\
+             .........1.\n\
+             .......1...",
+            Frame::after(&robots, EXAMPLE, 0).to_string()
+        );
+        assert_eq!(
+            "......2..1.\n\
+             ...........\n\
+             1..........\n\
+             .11........\n\
+             .....1.....\n\
+             ...12......\n\
+             .1....1....",
+            Frame::after(&robots, EXAMPLE, SECONDS).to_string()
+        );
+    }
+
+    #[test]
+    fn move_in_closed_form() {
+        let robot = parse_line("p=2,4 v=2,-3").unwrap();
+
+        let stepped = (0..5).fold(robot.position, |(x, y), _| {
+            ((x + 2).rem_euclid(11), (y - 3).rem_euclid(7))
+        });
+        assert_eq!((1, 3), stepped);
+        assert_eq!(stepped, robot.after(EXAMPLE, 5));
+        assert_eq!(robot.after(EXAMPLE, 5), robot.after(EXAMPLE, 5 + 77 * 1000));
+
+        let fast = Robot {
+            position: (0, 0),
+            velocity: (i64::MAX, i64::MIN),
+        };
+        assert_eq!((6, 6), fast.after(EXAMPLE, u64::MAX));
+    }
+
+    #[test]
+    fn find_planted_picture() {
+        // Eight robots that meet in a square at second 1234, and one that
+        // wanders elsewhere.
+        let seconds: i64 = 1234;
+        let velocities = [
+            (37, -12),
+            (-81, 44),
+            (5, 93),
+            (-23, -67),
+            (62, 8),
+            (-49, 71),
+            (88, -35),
+            (14, -90),
+        ];
+        let mut robots = (0..8)
+            .zip(velocities)
+            .map(|(idx, velocity)| {
+                let target = (50 + idx % 3, 50 + idx / 3);
+                Robot {
+                    position: (
+                        (target.0 - velocity.0 * seconds).rem_euclid(101),
+                        (target.1 - velocity.1 * seconds).rem_euclid(103),
+                    ),
+                    velocity,
+                }
+            })
+            .collect::<Vec<_>>();
+        robots.push(Robot {
+            position: (3, 4),
+            velocity: (1, 1),
+        });
+
+        assert_eq!(Some(seconds as u64), picture(&robots, Space::BATHROOM));
+        assert_eq!(
+            None,
+            picture(
+                &robots,
+                Space {
+                    width: 10,
+                    height: 4
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn parse_rejects_malformed_robots() {
+        assert_eq!(
+            ParseError::new(2, 7, "v=1;2", "expected `v=x,y`"),
+            parse("p=0,0 v=1,1\np=0,0 v=1;2").unwrap_err()
+        );
+        assert_eq!(
+            ParseError::new(1, 5, "x", "expected a number"),
+            parse("p=0,x v=1,1").unwrap_err()
+        );
+    }
+}
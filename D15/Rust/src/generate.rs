@@ -0,0 +1,108 @@
+use common::generate::{Generator, Rng};
+
+use crate::Day15;
+
+/// Shape of a generated warehouse and move list.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// Rows and columns of the map, including its outer wall.
+    pub size: usize,
+    /// Percentage of inner tiles that are walls.
+    pub walls: u64,
+    /// Percentage of inner tiles that hold a box.
+    pub boxes: u64,
+    pub moves: usize,
+}
+
+impl Generator for Day15 {
+    type Config = Config;
+
+    const SIZE: usize = 50;
+
+    fn config(size: usize) -> Config {
+        Config {
+            size: size.max(3),
+            walls: 5,
+            boxes: 40,
+            moves: size * 400,
+        }
+    }
+
+    fn generate(rng: &mut Rng, config: &Config) -> String {
+        let size = config.size;
+        let inner = |index: usize| index > 0 && index < size - 1;
+
+        let mut tiles = (0..size)
+            .map(|row| {
+                (0..size)
+                    .map(|col| {
+                        if !inner(row) || !inner(col) || rng.percent(config.walls) {
+                            '#'
+                        } else if rng.percent(config.boxes) {
+                            'O'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let (row, col) = (1 + rng.index(size - 2), 1 + rng.index(size - 2));
+        tiles[row][col] = '@';
+
+        let mut input = tiles
+            .into_iter()
+            .map(|row| row.into_iter().collect::<String>() + "\n")
+            .collect::<String>();
+        input.push('\n');
+
+        let moves = (0..config.moves)
+            .map(|_| *rng.pick(&['^', 'v', '<', '>']))
+            .collect::<Vec<_>>();
+        for line in moves.chunks(70) {
+            input.extend(line);
+            input.push('\n');
+        }
+
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{
+        generate::{Generator, Rng},
+        Solution,
+    };
+
+    use crate::{Cell, Day15, Warehouse};
+
+    #[test]
+    fn generate_warehouse() {
+        let config = Day15::config(20);
+        let input = Day15::generate(&mut Rng::new(15), &config);
+
+        assert_eq!(input, Day15::generate(&mut Rng::new(15), &config));
+
+        let parsed = Day15::parse(&input).unwrap();
+        let (warehouse, moves) = &parsed;
+        assert_eq!((20, 20), (warehouse.grid.rows(), warehouse.grid.cols()));
+        assert_eq!(config.moves, moves.len());
+
+        // Pushing never loses or merges boxes.
+        let boxes = |warehouse: &Warehouse| {
+            warehouse
+                .grid
+                .iter()
+                .filter(|(_, &cell)| cell == Cell::Box)
+                .count()
+        };
+        let mut moved = warehouse.clone();
+        moved.run(moves);
+        assert_eq!(boxes(warehouse), boxes(&moved));
+
+        assert!(Day15::part_one(&parsed) > 0);
+        assert!(Day15::part_two(&parsed) > 0);
+    }
+}
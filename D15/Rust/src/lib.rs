@@ -0,0 +1,453 @@
+pub mod generate;
+
+use std::collections::HashSet;
+
+use common::{ParseError, Solution};
+use grid::{Direction, Grid, Position};
+
+pub struct Day15;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Cell {
+    Empty,
+    Wall,
+    Box,
+    /// Left half of a box in the widened warehouse.
+    BoxLeft,
+    /// Right half of a box in the widened warehouse.
+    BoxRight,
+}
+
+/// The warehouse map and where the robot stands on it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warehouse {
+    pub grid: Grid<Cell>,
+    pub robot: Position,
+}
+
+impl Warehouse {
+    /// Moves the robot one tile in `direction`, pushing any boxes in the way,
+    /// unless a wall stops them. Returns where the robot ends up, or `None`
+    /// if it would walk off the map.
+    pub fn step(&mut self, direction: Direction) -> Option<Position> {
+        let next = self.grid.offset(self.robot, direction.offset())?;
+
+        if let Some(boxes) = self.pushed(next, direction) {
+            // Can move forward, taking the boxes along
+            self.shift(boxes, direction);
+            self.robot = next;
+        }
+
+        Some(self.robot)
+    }
+
+    /// Makes every move in turn.
+    pub fn run(&mut self, moves: &[Direction]) {
+        for &direction in moves {
+            self.step(direction);
+        }
+    }
+
+    /// The tiles holding a box that would move if something entered
+    /// `position` heading in `direction`, or `None` if a wall blocks them.
+    fn pushed(&self, position: Position, direction: Direction) -> Option<Vec<Position>> {
+        let mut boxes = Vec::new();
+        let mut seen = HashSet::new();
+
+        self.push(position, direction, &mut boxes, &mut seen)
+            .then_some(boxes)
+    }
+
+    fn push(
+        &self,
+        position: Position,
+        direction: Direction,
+        boxes: &mut Vec<Position>,
+        seen: &mut HashSet<Position>,
+    ) -> bool {
+        if !seen.insert(position) {
+            return true;
+        }
+
+        let vertical = matches!(direction, Direction::Up | Direction::Down);
+
+        // The other half of a wide box moves with it when pushed up or down;
+        // pushed sideways, it is next in line anyway.
+        let other_half = match self.grid.get(position) {
+            None | Some(Cell::Wall) => return false,
+            Some(Cell::Empty) => return true,
+            Some(Cell::Box) => None,
+            Some(Cell::BoxLeft) => vertical.then_some(Direction::Right),
+            Some(Cell::BoxRight) => vertical.then_some(Direction::Left),
+        };
+
+        boxes.push(position);
+
+        let ahead = self.grid.offset(position, direction.offset());
+        let beside = other_half.and_then(|side| self.grid.offset(position, side.offset()));
+
+        ahead.is_some_and(|ahead| self.push(ahead, direction, boxes, seen))
+            && beside.is_none_or(|beside| self.push(beside, direction, boxes, seen))
+    }
+
+    /// Moves the `boxes` tiles one step in `direction`, furthest first so
+    /// that none is overwritten before it moves.
+    fn shift(&mut self, mut boxes: Vec<Position>, direction: Direction) {
+        boxes.sort_by_key(|&(row, col)| match direction {
+            Direction::Up => (row, 0),
+            Direction::Down => (usize::MAX - row, 0),
+            Direction::Left => (0, col),
+            Direction::Right => (0, usize::MAX - col),
+        });
+
+        for position in boxes {
+            let next = self
+                .grid
+                .offset(position, direction.offset())
+                .expect("pushed boxes stay on the map");
+            self.grid[next] = self.grid[position];
+            self.grid[position] = Cell::Empty;
+        }
+    }
+
+    /// The same warehouse with everything except the robot twice as wide.
+    pub fn widen(&self) -> Self {
+        let cells = self
+            .grid
+            .iter()
+            .flat_map(|(_, cell)| match cell {
+                Cell::Box | Cell::BoxLeft | Cell::BoxRight => [Cell::BoxLeft, Cell::BoxRight],
+                &cell => [cell, cell],
+            })
+            .collect();
+
+        Self {
+            grid: Grid::new(self.grid.rows(), self.grid.cols() * 2, cells),
+            robot: (self.robot.0, self.robot.1 * 2),
+        }
+    }
+
+    /// Sum of the GPS coordinates of every box: 100 times its distance from
+    /// the top edge plus its distance from the left edge, measured to the
+    /// left half of a wide box.
+    pub fn gps(&self) -> usize {
+        self.grid
+            .iter()
+            .filter(|(_, &cell)| cell == Cell::Box || cell == Cell::BoxLeft)
+            .map(|((row, col), _)| 100 * row + col)
+            .sum()
+    }
+
+    /// The warehouse after each of `moves` in turn.
+    pub fn steps<'m>(&self, moves: &'m [Direction]) -> Steps<'m> {
+        Steps {
+            warehouse: self.clone(),
+            moves: moves.iter(),
+        }
+    }
+}
+
+/// Iterator over the states of a warehouse as the robot makes its moves,
+/// yielding a copy of the warehouse after each one.
+pub struct Steps<'m> {
+    warehouse: Warehouse,
+    moves: std::slice::Iter<'m, Direction>,
+}
+
+impl Iterator for Steps<'_> {
+    type Item = Warehouse;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let &direction = self.moves.next()?;
+        self.warehouse.step(direction);
+
+        Some(self.warehouse.clone())
+    }
+}
+
+impl std::fmt::Display for Warehouse {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut result = String::new();
+
+        for (position, cell) in self.grid.iter() {
+            if position.1 == 0 && position.0 > 0 {
+                result.push('\n');
+            }
+            result.push(match cell {
+                _ if position == self.robot => '@',
+                Cell::Empty => '.',
+                Cell::Wall => '#',
+                Cell::Box => 'O',
+                Cell::BoxLeft => '[',
+                Cell::BoxRight => ']',
+            });
+        }
+
+        write!(f, "{}", result)
+    }
+}
+
+pub fn parse_warehouse(input: &str) -> Result<Warehouse, ParseError> {
+    let tiles = Grid::try_parse(input, |c| match c {
+        '#' | '.' | 'O' | '@' => Ok(c),
+        _ => Err("expected `#`, `.`, `O` or `@`"),
+    })?;
+
+    let mut robots = tiles.iter().filter(|(_, &c)| c == '@').map(|(p, _)| p);
+    let robot = robots
+        .next()
+        .ok_or_else(|| ParseError::new(1, 1, "", "map has no robot `@`"))?;
+    if let Some((row, col)) = robots.next() {
+        return Err(ParseError::new(
+            row + 1,
+            col + 1,
+            "@",
+            "map has a second robot",
+        ));
+    }
+
+    let grid = tiles.map(|&c| match c {
+        '#' => Cell::Wall,
+        'O' => Cell::Box,
+        _ => Cell::Empty,
+    });
+
+    Ok(Warehouse { grid, robot })
+}
+
+/// Parses the moves, which may be split over several lines, starting on the
+/// one-based line `first`.
+pub fn parse_moves(input: &str, first: usize) -> Result<Vec<Direction>, ParseError> {
+    let mut moves = Vec::new();
+
+    for (idx, line) in input.lines().enumerate() {
+        for (col, c) in line.chars().enumerate() {
+            let direction = match c {
+                '^' => Direction::Up,
+                'v' => Direction::Down,
+                '<' => Direction::Left,
+                '>' => Direction::Right,
+                c if c.is_whitespace() => continue,
+                c => {
+                    return Err(ParseError::new(
+                        first + idx,
+                        col + 1,
+                        &c.to_string(),
+                        "expected a move `^`, `v`, `<` or `>`",
+                    ))
+                }
+            };
+            moves.push(direction);
+        }
+    }
+
+    Ok(moves)
+}
+
+/// Parses the map and, after a blank line, the robot's moves.
+pub fn parse(input: &str) -> Result<(Warehouse, Vec<Direction>), ParseError> {
+    let lines = input.lines().collect::<Vec<_>>();
+    let blank = lines
+        .iter()
+        .position(|line| line.trim().is_empty())
+        .unwrap_or(lines.len());
+
+    let warehouse = parse_warehouse(&lines[..blank].join("\n"))?;
+    let moves = match lines.get(blank + 1..) {
+        Some(rest) => parse_moves(&rest.join("\n"), blank + 2)?,
+        None => Vec::new(),
+    };
+
+    Ok((warehouse, moves))
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = (Warehouse, Vec<Direction>);
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    /// GPS sum once the robot has made every move.
+    fn part_one((warehouse, moves): &Self::Input) -> usize {
+        let mut warehouse = warehouse.clone();
+        warehouse.run(moves);
+        warehouse.gps()
+    }
+
+    /// GPS sum once the robot has made every move in the widened warehouse.
+    fn part_two((warehouse, moves): &Self::Input) -> usize {
+        let mut warehouse = warehouse.widen();
+        warehouse.run(moves);
+        warehouse.gps()
+    }
+}
+
+pub fn solve_part_one(input: &str) -> Result<usize, ParseError> {
+    Ok(Day15::part_one(&parse(input)?))
+}
+
+pub fn solve_part_two(input: &str) -> Result<usize, ParseError> {
+    Ok(Day15::part_two(&parse(input)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use common::ParseError;
+
+    use super::*;
+
+    static SAMPLE: &str = r"##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
+
+    static SMALL: &str = r"########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<";
+
+    static WIDE: &str = r"#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^";
+
+    #[test]
+    fn solve_sample_one() {
+        assert_eq!(10092, solve_part_one(SAMPLE).unwrap());
+    }
+
+    #[test]
+    fn solve_sample_two() {
+        assert_eq!(9021, solve_part_two(SAMPLE).unwrap());
+    }
+
+    #[test]
+    fn push_single_boxes() {
+        let (warehouse, moves) = parse(SMALL).unwrap();
+
+        let states = warehouse.steps(&moves).collect::<Vec<_>>();
+        assert_eq!(moves.len(), states.len());
+        assert_eq!(
+            "########\n\
+             #...@OO#\n\
+             ##..O..#\n\
+             #...O..#\n\
+             #.#.O..#\n\
+             #...O..#\n\
+             #......#\n\
+             ########",
+            states[4].to_string()
+        );
+
+        let last = states.last().unwrap();
+        assert_eq!(
+            "########\n\
+             #....OO#\n\
+             ##.....#\n\
+             #.....O#\n\
+             #.#O@..#\n\
+             #...O..#\n\
+             #...O..#\n\
+             ########",
+            last.to_string()
+        );
+        assert_eq!(2028, last.gps());
+    }
+
+    #[test]
+    fn push_wide_boxes() {
+        let (warehouse, moves) = parse(WIDE).unwrap();
+        let mut warehouse = warehouse.widen();
+
+        assert_eq!(
+            "##############\n\
+             ##......##..##\n\
+             ##..........##\n\
+             ##....[][]@.##\n\
+             ##....[]....##\n\
+             ##..........##\n\
+             ##############",
+            warehouse.to_string()
+        );
+
+        warehouse.run(&moves);
+        assert_eq!(
+            "##############\n\
+             ##...[].##..##\n\
+             ##...@.[]...##\n\
+             ##....[]....##\n\
+             ##..........##\n\
+             ##..........##\n\
+             ##############",
+            warehouse.to_string()
+        );
+        assert_eq!(105 + 207 + 306, warehouse.gps());
+    }
+
+    #[test]
+    fn blocked_pushes_move_nothing() {
+        let (mut warehouse, _) = parse("#####\n#.O.#\n#.O.#\n#.@.#\n#####").unwrap();
+        let before = warehouse.clone();
+
+        assert_eq!(Some((3, 2)), warehouse.step(Direction::Up));
+        assert_eq!(before, warehouse);
+
+        // Pushed from either half, the lower wide box takes the upper one
+        // with it, and the wall stops them both.
+        let (warehouse, _) = parse("#####\n#.#.#\n#.O.#\n#.O.#\n#.@.#\n#####").unwrap();
+        let mut wide = warehouse.widen();
+
+        assert_eq!(Some((4, 4)), wide.step(Direction::Up));
+        assert_eq!(Some((4, 5)), wide.step(Direction::Right));
+        assert_eq!(Some((4, 5)), wide.step(Direction::Up));
+        assert_eq!(warehouse.widen().grid, wide.grid);
+    }
+
+    #[test]
+    fn parse_rejects_unknown_cells() {
+        assert_eq!(
+            ParseError::new(2, 3, "x", "expected `#`, `.`, `O` or `@`"),
+            parse("####\n#@x#\n####").unwrap_err()
+        );
+        assert_eq!(
+            ParseError::new(2, 3, "@", "map has a second robot"),
+            parse("####\n#@@#\n####").unwrap_err()
+        );
+        assert_eq!(
+            ParseError::new(6, 2, "x", "expected a move `^`, `v`, `<` or `>`"),
+            parse("####\n#@.#\n####\n\n<<\n<x").unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,23 @@
+use std::path::Path;
+
+use common::{input::read_input, Solution};
+use d15::{solve_part_one, solve_part_two, Day15};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let input_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../input.txt");
+    let data = read_input(
+        Day15::DAY,
+        std::env::args().nth(1).as_deref(),
+        &[input_path],
+    )?;
+
+    let a1 = solve_part_one(&data)?;
+
+    println!("{a1}");
+
+    let a2 = solve_part_two(&data)?;
+
+    println!("{a2}");
+
+    Ok(())
+}
@@ -0,0 +1,179 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt::Display,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use serde::{Deserialize, Serialize};
+
+static COUNT: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+static CURRENT: AtomicU64 = AtomicU64::new(0);
+static PEAK: AtomicU64 = AtomicU64::new(0);
+
+/// The system allocator, counting every allocation it hands out. Installed as
+/// the global allocator when the `alloc-stats` feature is on.
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn record(&self, size: usize) {
+        let size = size as u64;
+
+        COUNT.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(size, Ordering::Relaxed);
+
+        let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            self.record(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            self.record(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+    }
+
+    /// A reallocation counts as one allocation of the new size, the way a
+    /// `Vec` growing past its capacity would without `realloc`.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            CURRENT.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+            self.record(new_size);
+        }
+        new
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Whether allocations are being counted, i.e. whether the `alloc-stats`
+/// feature is on.
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+/// Heap use of one stage of a solution.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Allocations {
+    /// Number of allocations, including reallocations.
+    pub count: u64,
+    /// Total bytes requested across all of them.
+    pub bytes: u64,
+    /// Most heap in use at once, above what was in use beforehand.
+    pub peak: u64,
+}
+
+impl Display for Allocations {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, {} peak",
+            self.count,
+            Bytes(self.bytes),
+            Bytes(self.peak)
+        )
+    }
+}
+
+struct Bytes(u64);
+
+impl Display for Bytes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+        let mut size = self.0 as f64;
+        let mut unit = 0;
+        while size >= 1024.0 && unit + 1 < UNITS.len() {
+            size /= 1024.0;
+            unit += 1;
+        }
+
+        if unit == 0 {
+            write!(f, "{} B", self.0)
+        } else {
+            write!(f, "{size:.1} {}", UNITS[unit])
+        }
+    }
+}
+
+/// Runs `f` and reports what it allocated, or `None` when allocations are
+/// not being counted.
+///
+/// The counters are process-wide, so allocations made on other threads at
+/// the same time, such as other days under the `parallel` feature, are
+/// included too.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Allocations>) {
+    if !ENABLED {
+        return (f(), None);
+    }
+
+    let count = COUNT.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+    let current = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(current, Ordering::Relaxed);
+
+    let value = f();
+
+    let allocations = Allocations {
+        count: COUNT.load(Ordering::Relaxed) - count,
+        bytes: BYTES.load(Ordering::Relaxed) - bytes,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(current),
+    };
+
+    (value, Some(allocations))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{measure, Allocations, Bytes, ENABLED};
+
+    #[test]
+    fn measure_counts_allocations() {
+        let (sum, allocations) = measure(|| {
+            let values = vec![1u64; 1024];
+            let more = vec![2u64; 1024];
+            values.iter().sum::<u64>() + more.iter().sum::<u64>()
+        });
+
+        assert_eq!(3072, sum);
+
+        assert_eq!(ENABLED, allocations.is_some());
+        if let Some(allocations) = allocations {
+            assert!(allocations.count >= 2);
+            assert!(allocations.bytes >= 2 * 8192);
+            assert!(allocations.peak >= 2 * 8192);
+        }
+    }
+
+    #[test]
+    fn display_scales_units() {
+        let allocations = Allocations {
+            count: 3,
+            bytes: 3 * 1024 * 1024,
+            peak: 512,
+        };
+
+        assert_eq!(
+            "3 allocations, 3.0 MiB allocated, 512 B peak",
+            allocations.to_string()
+        );
+        assert_eq!("1.5 KiB", Bytes(1536).to_string());
+    }
+}
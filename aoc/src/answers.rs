@@ -0,0 +1,247 @@
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use common::Part;
+use serde::Deserialize;
+
+/// An accepted answer for one part of one day, tied to the input it was
+/// computed from.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Expected {
+    pub day: u8,
+    pub part: u8,
+    /// `common::input::hash` of the puzzle input, or `None` for answers read
+    /// from a per-account file under `answers/`, which only ever sees that
+    /// account's input.
+    pub input: Option<String>,
+    pub answer: String,
+}
+
+/// An `[[answer]]` table in the shared registry, which must name its input.
+#[derive(Debug, Deserialize)]
+struct RegistryAnswer {
+    day: u8,
+    part: u8,
+    input: String,
+    answer: String,
+}
+
+/// An `[[answer]]` table in a per-account file, where the input is implied.
+#[derive(Debug, Deserialize)]
+struct AccountAnswer {
+    day: u8,
+    part: u8,
+    answer: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnswersFile<A> {
+    #[serde(default = "Vec::new", rename = "answer")]
+    answers: Vec<A>,
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    Parse {
+        path: PathBuf,
+        error: toml::de::Error,
+    },
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Io { path, error } => write!(f, "failed to read {}: {error}", path.display()),
+            Self::Parse { path, error } => {
+                write!(f, "failed to parse {}: {error}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+/// Registry of expected answers loaded from a TOML file of `[[answer]]`
+/// tables.
+#[derive(Debug, Default)]
+pub struct Answers(Vec<Expected>);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Pass => write!(f, "pass"),
+            Self::Fail { expected } => write!(f, "FAIL (expected {expected})"),
+            Self::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl Answers {
+    /// Parses a shared registry, where every answer names its input hash.
+    pub fn parse(source: &str) -> Result<Self, toml::de::Error> {
+        let file = toml::from_str::<AnswersFile<RegistryAnswer>>(source)?;
+
+        Ok(Self(
+            file.answers
+                .into_iter()
+                .map(|a| Expected {
+                    day: a.day,
+                    part: a.part,
+                    input: Some(a.input),
+                    answer: a.answer,
+                })
+                .collect(),
+        ))
+    }
+
+    /// Parses a per-account file, whose answers match any input.
+    pub fn parse_account(source: &str) -> Result<Self, toml::de::Error> {
+        let file = toml::from_str::<AnswersFile<AccountAnswer>>(source)?;
+
+        Ok(Self(
+            file.answers
+                .into_iter()
+                .map(|a| Expected {
+                    day: a.day,
+                    part: a.part,
+                    input: None,
+                    answer: a.answer,
+                })
+                .collect(),
+        ))
+    }
+
+    /// Loads the registry at `path`; a missing file is an empty registry.
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        Self::read(path, Self::parse)
+    }
+
+    /// Loads a per-account file like `load`, with `parse_account`.
+    pub fn load_account(path: &Path) -> Result<Self, AnswersError> {
+        Self::read(path, Self::parse_account)
+    }
+
+    fn read(
+        path: &Path,
+        parse: fn(&str) -> Result<Self, toml::de::Error>,
+    ) -> Result<Self, AnswersError> {
+        match std::fs::read_to_string(path) {
+            Ok(source) => parse(&source).map_err(|error| AnswersError::Parse {
+                path: path.to_owned(),
+                error,
+            }),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(AnswersError::Io {
+                path: path.to_owned(),
+                error,
+            }),
+        }
+    }
+
+    pub fn get(&self, day: u8, part: Part, input: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|e| {
+                e.day == day
+                    && e.part == part.number()
+                    && e.input.as_deref().is_none_or(|i| i == input)
+            })
+            .map(|e| e.answer.as_str())
+    }
+
+    pub fn verify(&self, day: u8, part: Part, input: &str, actual: &str) -> Verdict {
+        match self.get(day, part, input) {
+            Some(expected) if expected == actual => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_owned(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part;
+
+    use super::{Answers, Verdict};
+
+    static ANSWERS: &str = r#"
+[[answer]]
+day = 9
+part = 1
+input = "0123456789abcdef"
+answer = "1928"
+
+[[answer]]
+day = 9
+part = 2
+input = "0123456789abcdef"
+answer = "2858"
+"#;
+
+    #[test]
+    fn verify_by_day_part_and_input() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+
+        assert_eq!(
+            Verdict::Pass,
+            answers.verify(9, Part::One, "0123456789abcdef", "1928")
+        );
+        assert_eq!(
+            Verdict::Fail {
+                expected: "2858".to_owned()
+            },
+            answers.verify(9, Part::Two, "0123456789abcdef", "1928")
+        );
+        assert_eq!(
+            Verdict::Unknown,
+            answers.verify(9, Part::One, "fedcba9876543210", "1928")
+        );
+        assert_eq!(
+            Verdict::Unknown,
+            answers.verify(11, Part::One, "0123456789abcdef", "1928")
+        );
+    }
+
+    #[test]
+    fn registry_answers_need_an_input() {
+        let source = "[[answer]]\nday = 1\npart = 2\nanswer = \"31\"\n";
+
+        let error = Answers::parse(source).unwrap_err();
+        assert!(error.to_string().contains("missing field `input`"));
+
+        let answers = Answers::parse_account(source).unwrap();
+        assert_eq!(Some("31"), answers.get(1, Part::Two, "0123456789abcdef"));
+        assert_eq!(None, answers.get(1, Part::One, "0123456789abcdef"));
+    }
+
+    #[test]
+    fn empty_file_has_no_answers() {
+        let answers = Answers::parse("").unwrap();
+
+        assert_eq!(None, answers.get(1, Part::One, "0123456789abcdef"));
+    }
+
+    #[test]
+    fn checked_in_answers_parse() {
+        Answers::load(&crate::answers_path()).unwrap();
+
+        let answers = Answers::load_account(&crate::answers_dir().join("maintainer.toml")).unwrap();
+        assert_eq!(Some("6511178035564"), answers.get(9, Part::Two, "anything"));
+        assert_eq!(Some("224529"), answers.get(11, Part::One, "anything"));
+    }
+}
@@ -0,0 +1,119 @@
+use std::{fmt::Display, time::Duration};
+
+use crate::config::Config;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "github.com/N-Hoque/aoc2024 aoc runner";
+
+#[derive(Debug)]
+pub enum ClientError {
+    Status { url: String, code: u16 },
+    Transport { url: String, error: String },
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Status { url, code } => write!(f, "{url} responded with status {code}"),
+            Self::Transport { url, error } => write!(f, "failed to reach {url}: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+/// Minimal Advent of Code HTTP client authenticated with a session cookie.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session: session.to_owned(),
+        }
+    }
+
+    pub fn from_config(config: &Config) -> Self {
+        Self::new(BASE_URL, &config.session_cookie)
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    fn handle(
+        url: String,
+        response: Result<ureq::Response, ureq::Error>,
+    ) -> Result<String, ClientError> {
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|error| ClientError::Transport {
+                    url,
+                    error: error.to_string(),
+                }),
+            Err(ureq::Error::Status(code, _)) => Err(ClientError::Status { url, code }),
+            Err(error) => Err(ClientError::Transport {
+                url,
+                error: error.to_string(),
+            }),
+        }
+    }
+
+    pub fn get(&self, path: &str) -> Result<String, ClientError> {
+        let url = self.url(path);
+        let response = self.agent.get(&url).set("Cookie", &self.cookie()).call();
+
+        Self::handle(url, response)
+    }
+
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String, ClientError> {
+        let url = self.url(path);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(form);
+
+        Self::handle(url, response)
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        self.get(&format!("/{year}/day/{day}/input"))
+    }
+
+    /// The puzzle page for `day`, whose articles hold the description.
+    pub fn description(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        self.get(&format!("/{year}/day/{day}"))
+    }
+
+    pub fn answer(
+        &self,
+        year: u16,
+        day: u8,
+        level: u8,
+        answer: &str,
+    ) -> Result<String, ClientError> {
+        let level = level.to_string();
+
+        self.post_form(
+            &format!("/{year}/day/{day}/answer"),
+            &[("level", &level), ("answer", answer)],
+        )
+    }
+}
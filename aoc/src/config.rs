@@ -0,0 +1,207 @@
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use common::input::YEAR;
+use serde::Deserialize;
+
+/// Environment variable pointing at an `aocdl` style config file.
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+
+/// Value shipped in `.aocdlconfig.shim` before a real cookie is filled in.
+const PLACEHOLDER_COOKIE: &str = "TO_BE_FILLED_IN";
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Config {
+    #[serde(rename = "session-cookie")]
+    pub session_cookie: String,
+    pub year: u16,
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    NotFound {
+        tried: Vec<PathBuf>,
+    },
+    Io {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    Parse {
+        path: PathBuf,
+        error: serde_json::Error,
+    },
+    MissingCookie {
+        path: PathBuf,
+    },
+    /// The solutions and the input cache only cover `common::input::YEAR`.
+    UnsupportedYear {
+        path: PathBuf,
+        year: u16,
+    },
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::NotFound { tried } => {
+                write!(f, "no config found; tried ")?;
+                for (idx, path) in tried.iter().enumerate() {
+                    if idx > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", path.display())?;
+                }
+                Ok(())
+            }
+            Self::Io { path, error } => write!(f, "failed to read {}: {error}", path.display()),
+            Self::Parse { path, error } => {
+                write!(f, "failed to parse {}: {error}", path.display())
+            }
+            Self::MissingCookie { path } => {
+                write!(f, "session-cookie is not filled in in {}", path.display())
+            }
+            Self::UnsupportedYear { path, year } => write!(
+                f,
+                "year {year} in {} is not supported; only {YEAR} is solved",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl Config {
+    pub fn parse(source: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(&strip_trailing_commas(source))
+    }
+
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        let source = std::fs::read_to_string(path).map_err(|error| ConfigError::Io {
+            path: path.to_owned(),
+            error,
+        })?;
+
+        let config = Self::parse(&source).map_err(|error| ConfigError::Parse {
+            path: path.to_owned(),
+            error,
+        })?;
+
+        if config.session_cookie.is_empty() || config.session_cookie == PLACEHOLDER_COOKIE {
+            return Err(ConfigError::MissingCookie {
+                path: path.to_owned(),
+            });
+        }
+
+        if config.year != YEAR {
+            return Err(ConfigError::UnsupportedYear {
+                path: path.to_owned(),
+                year: config.year,
+            });
+        }
+
+        Ok(config)
+    }
+
+    /// Loads the first config out of `AOC_CONFIG`, the workspace
+    /// `.aocdlconfig.shim` and `~/.aocdlconfig`.
+    pub fn locate() -> Result<Self, ConfigError> {
+        let tried = std::env::var_os(CONFIG_VAR)
+            .map(PathBuf::from)
+            .into_iter()
+            .chain([Path::new(env!("CARGO_MANIFEST_DIR")).join("../.aocdlconfig.shim")])
+            .chain(std::env::var_os("HOME").map(|home| Path::new(&home).join(".aocdlconfig")))
+            .collect::<Vec<_>>();
+
+        match tried.iter().find(|path| path.is_file()) {
+            Some(path) => Self::load(path),
+            None => Err(ConfigError::NotFound { tried }),
+        }
+    }
+}
+
+/// `aocdl` accepts JSON with trailing commas, which `serde_json` rejects, so
+/// drop any comma that is only followed by whitespace and a closing bracket.
+fn strip_trailing_commas(source: &str) -> String {
+    let mut result = String::with_capacity(source.len());
+    let mut chars = source.chars();
+    let mut in_string = false;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => in_string = !in_string,
+            '\\' if in_string => {
+                result.push(c);
+                if let Some(escaped) = chars.next() {
+                    result.push(escaped);
+                }
+                continue;
+            }
+            ',' if !in_string => {
+                let rest = chars.clone().find(|c| !c.is_whitespace());
+                if matches!(rest, Some('}' | ']')) {
+                    continue;
+                }
+            }
+            _ => {}
+        }
+
+        result.push(c);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{strip_trailing_commas, Config};
+
+    static SHIM: &str = "{\n\t\"session-cookie\": \"TO_BE_FILLED_IN\",\n\t\"year\": 2024,\n}\n";
+
+    #[test]
+    fn parse_shim() {
+        let config = Config::parse(SHIM).unwrap();
+
+        assert_eq!("TO_BE_FILLED_IN", config.session_cookie);
+        assert_eq!(2024, config.year);
+    }
+
+    #[test]
+    fn strip_keeps_commas_in_strings() {
+        assert_eq!(
+            r#"{"a": ",}", "b": [1, 2]}"#,
+            strip_trailing_commas(r#"{"a": ",}", "b": [1, 2,],}"#)
+        );
+        assert_eq!(
+            r#"{"a": "\",}"}"#,
+            strip_trailing_commas(r#"{"a": "\",}",}"#)
+        );
+    }
+
+    #[test]
+    fn load_rejects_placeholder_cookie() {
+        let path = std::env::temp_dir().join(format!("aoc-config-{}.json", std::process::id()));
+        std::fs::write(&path, SHIM).unwrap();
+
+        let error = Config::load(&path).unwrap_err();
+
+        assert!(error.to_string().contains("session-cookie"));
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn load_rejects_other_years() {
+        let path =
+            std::env::temp_dir().join(format!("aoc-config-year-{}.json", std::process::id()));
+        std::fs::write(&path, r#"{"session-cookie": "abc", "year": 2023}"#).unwrap();
+
+        let error = Config::load(&path).unwrap_err();
+
+        assert!(error.to_string().contains("year 2023"));
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,259 @@
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use common::input::{cache_path, description_path};
+
+use crate::client::{Client, ClientError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Download {
+    /// The input was already cached and no request was made.
+    Cached(PathBuf),
+    /// The input was downloaded and written to the cache.
+    Fetched(PathBuf),
+}
+
+impl Download {
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Cached(path) | Self::Fetched(path) => path,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    Client(ClientError),
+    Io {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Client(error) => write!(f, "{error}"),
+            Self::Io { path, error } => write!(f, "failed to write {}: {error}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+impl From<ClientError> for FetchError {
+    fn from(error: ClientError) -> Self {
+        Self::Client(error)
+    }
+}
+
+/// Downloads the input for `day` into the cache unless it is already there.
+pub fn fetch_input(
+    client: &Client,
+    cache_dir: &Path,
+    year: u16,
+    day: u8,
+) -> Result<Download, FetchError> {
+    let path = cache_path(cache_dir, year, day);
+
+    if path.is_file() {
+        return Ok(Download::Cached(path));
+    }
+
+    let input = client.input(year, day)?;
+    save(&path, &input)?;
+
+    Ok(Download::Fetched(path))
+}
+
+/// Downloads the puzzle description for `day` into the cache as plain text,
+/// where `aoc new` picks it up, unless it is already there.
+pub fn fetch_description(
+    client: &Client,
+    cache_dir: &Path,
+    year: u16,
+    day: u8,
+) -> Result<Download, FetchError> {
+    let path = description_path(cache_dir, year, day);
+
+    if path.is_file() {
+        return Ok(Download::Cached(path));
+    }
+
+    let page = client.description(year, day)?;
+    save(&path, &articles_text(&page))?;
+
+    Ok(Download::Fetched(path))
+}
+
+fn save(path: &Path, contents: &str) -> Result<(), FetchError> {
+    let io_error = |error| FetchError::Io {
+        path: path.to_owned(),
+        error,
+    };
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(io_error)?;
+    }
+
+    // Write next to the destination first so an interrupted download never
+    // leaves a truncated file behind in the cache.
+    let partial = path.with_extension("part");
+    std::fs::write(&partial, contents).map_err(io_error)?;
+    std::fs::rename(&partial, path).map_err(io_error)
+}
+
+/// The text of every `<article>` on a puzzle page, laid out like the stored
+/// `desc.txt` files: headings and list items on their own lines, paragraphs
+/// followed by a blank line and examples kept as they are.
+fn articles_text(page: &str) -> String {
+    let mut text = String::new();
+    let (mut in_article, mut in_pre) = (false, false);
+    let mut rest = page;
+
+    while let Some(start) = rest.find('<') {
+        // Outside examples, line breaks between tags are only markup.
+        let between = &rest[..start];
+        if in_article && (in_pre || !between.trim().is_empty() || !between.contains('\n')) {
+            text.push_str(&decode(between));
+        }
+
+        let Some(end) = rest[start..].find('>') else {
+            break;
+        };
+        let tag = &rest[start + 1..start + end];
+        rest = &rest[start + end + 1..];
+
+        let name = tag
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .find(|part| !part.is_empty())
+            .unwrap_or_default();
+
+        match (tag.starts_with('/'), name) {
+            (false, "article") => in_article = true,
+            (true, "article") => {
+                in_article = false;
+                text.push_str("\n\n");
+            }
+            (false, "pre") => in_pre = true,
+            (true, "pre") => {
+                in_pre = false;
+                if in_article && !text.ends_with('\n') {
+                    text.push('\n');
+                }
+            }
+            (true, "h2" | "li") if in_article => text.push('\n'),
+            (true, "p") if in_article => text.push_str("\n\n"),
+            _ => {}
+        }
+    }
+
+    let mut lines = Vec::new();
+    for line in text.trim().lines() {
+        if !(line.is_empty() && lines.last().is_some_and(|last: &&str| last.is_empty())) {
+            lines.push(line);
+        }
+    }
+
+    lines.join("\n") + "\n"
+}
+
+fn decode(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use common::input::description_path;
+
+    use super::{fetch_description, fetch_input, Download};
+    use crate::{client::Client, mock::MockServer, scratch::scratch_dir};
+
+    #[test]
+    fn fetch_then_reuse_cache() {
+        let server = MockServer::start(vec![(200, "1 2 3\n".to_owned())]);
+        let client = Client::new(server.url(), "abc123");
+        let cache_dir = scratch_dir("fetch");
+
+        let first = fetch_input(&client, &cache_dir, 2024, 11).unwrap();
+        let second = fetch_input(&client, &cache_dir, 2024, 11).unwrap();
+
+        assert!(matches!(first, Download::Fetched(_)));
+        assert_eq!(Download::Cached(first.path().to_owned()), second);
+        assert_eq!("1 2 3\n", std::fs::read_to_string(first.path()).unwrap());
+
+        let requests = server.requests();
+        assert_eq!(1, requests.len());
+        assert_eq!("GET", requests[0].method);
+        assert_eq!("/2024/day/11/input", requests[0].path);
+        assert_eq!(Some("session=abc123"), requests[0].header("cookie"));
+        assert!(requests[0].body.is_empty());
+
+        std::fs::remove_dir_all(cache_dir).unwrap();
+    }
+
+    #[test]
+    fn fetch_description_as_text() {
+        let page = concat!(
+            "<html><body><main>\n<article class=\"day-desc\"><h2>--- Day 11: Plutonian Pebbles ---</h2>",
+            "<p>The stones change.</p>\n<ul>\n<li>If it is <code>0</code>, it becomes <code>1</code>.</li>\n",
+            "<li>Otherwise it is multiplied by <em>2024</em>.</li>\n</ul>\n<p>For example:</p>\n",
+            "<pre><code>125 17\n<em>253000</em> 1 7\n</code></pre>\n",
+            "<p>You would have <code><em>55312</em></code> stones &amp; more.</p>\n</article>\n",
+            "<p>Your puzzle answer was <code>224529</code>.</p>",
+            "<article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2><p>How many after <em>75</em>?</p>\n</article>\n",
+            "</main></body></html>"
+        );
+        let server = MockServer::start(vec![(200, page.to_owned())]);
+        let client = Client::new(server.url(), "abc123");
+        let cache_dir = scratch_dir("fetch-desc");
+
+        let first = fetch_description(&client, &cache_dir, 2024, 11).unwrap();
+        let second = fetch_description(&client, &cache_dir, 2024, 11).unwrap();
+
+        assert_eq!(
+            Download::Fetched(description_path(&cache_dir, 2024, 11)),
+            first
+        );
+        assert_eq!(Download::Cached(first.path().to_owned()), second);
+        assert_eq!(
+            "--- Day 11: Plutonian Pebbles ---\n\
+             The stones change.\n\n\
+             If it is 0, it becomes 1.\n\
+             Otherwise it is multiplied by 2024.\n\
+             For example:\n\n\
+             125 17\n\
+             253000 1 7\n\
+             You would have 55312 stones & more.\n\n\
+             --- Part Two ---\n\
+             How many after 75?\n",
+            std::fs::read_to_string(first.path()).unwrap()
+        );
+
+        let requests = server.requests();
+        assert_eq!(1, requests.len());
+        assert_eq!("/2024/day/11", requests[0].path);
+
+        std::fs::remove_dir_all(cache_dir).unwrap();
+    }
+
+    #[test]
+    fn failed_fetch_leaves_no_cache() {
+        let server = MockServer::start(vec![(400, "Please log in".to_owned())]);
+        let client = Client::new(server.url(), "expired");
+        let cache_dir = scratch_dir("fetch-fail");
+
+        let error = fetch_input(&client, &cache_dir, 2024, 3).unwrap_err();
+
+        assert!(error.to_string().contains("400"));
+        assert!(!common::input::cache_path(&cache_dir, 2024, 3).exists());
+
+        std::fs::remove_dir_all(cache_dir).unwrap();
+    }
+}
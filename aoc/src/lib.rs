@@ -0,0 +1,339 @@
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::allocations::{measure, Allocations};
+
+pub mod allocations;
+pub mod answers;
+pub mod client;
+pub mod config;
+pub mod fetch;
+#[cfg(test)]
+mod mock;
+pub mod parity;
+pub mod regression;
+pub mod scaffold;
+#[cfg(test)]
+mod scratch;
+pub mod submit;
+pub mod watch;
+
+pub use common::{generate::Generator, ParseError, Part, Solution};
+
+pub const DAYS: [u8; 15] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+
+/// The `DNN/input.txt` file each day's own binary reads.
+pub fn input_path(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("D{day:02}"))
+        .join("input.txt")
+}
+
+/// Answers for one day, with how long parsing and each part took.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Run {
+    pub day: u8,
+    #[serde(
+        rename = "parse_ns",
+        serialize_with = "nanos",
+        deserialize_with = "from_nanos"
+    )]
+    pub parse: Duration,
+    /// What parsing allocated, when built with `alloc-stats`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_alloc: Option<Allocations>,
+    pub parts: Vec<PartRun>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartRun {
+    #[serde(serialize_with = "part_number", deserialize_with = "from_part_number")]
+    pub part: Part,
+    pub answer: String,
+    #[serde(
+        rename = "time_ns",
+        serialize_with = "nanos",
+        deserialize_with = "from_nanos"
+    )]
+    pub time: Duration,
+    /// What the part allocated, when built with `alloc-stats`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alloc: Option<Allocations>,
+}
+
+impl Run {
+    pub fn answers(&self) -> impl Iterator<Item = &str> {
+        self.parts.iter().map(|p| p.answer.as_str())
+    }
+}
+
+fn nanos<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+fn from_nanos<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+    u64::deserialize(deserializer).map(Duration::from_nanos)
+}
+
+fn part_number<S: Serializer>(part: &Part, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u8(part.number())
+}
+
+fn from_part_number<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Part, D::Error> {
+    let number = u8::deserialize(deserializer)?;
+
+    Part::from_number(number).ok_or_else(|| serde::de::Error::custom(format!("no part {number}")))
+}
+
+fn run<S: Solution>(parts: &[Part], input: &str) -> Result<Run, ParseError> {
+    let start = Instant::now();
+    let (parsed, parse_alloc) = measure(|| S::parse(input));
+    let parse = start.elapsed();
+    let parsed = parsed?;
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let (answer, alloc) = measure(|| S::solve(&parsed, part));
+            let time = start.elapsed();
+
+            PartRun {
+                part,
+                answer: answer.to_string(),
+                time,
+                alloc,
+            }
+        })
+        .collect();
+
+    Ok(Run {
+        day: S::DAY,
+        parse,
+        parse_alloc,
+        parts,
+    })
+}
+
+/// The workspace directory holding the `DNN` folders.
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+/// Per-account inputs for `aoc regress`, as `inputs/<name>/dayNN.txt`.
+pub fn inputs_dir() -> PathBuf {
+    workspace_root().join("inputs")
+}
+
+/// Per-account answers for `aoc regress`, as `answers/<name>.toml`.
+pub fn answers_dir() -> PathBuf {
+    workspace_root().join("answers")
+}
+
+/// The expected-answer registry checked by `aoc verify`.
+pub fn answers_path() -> PathBuf {
+    workspace_root().join("answers.toml")
+}
+
+/// Parses `input` once for the given day and times the answer to each of
+/// `parts`, in order, or returns `None` if the day has not been solved.
+pub fn solve(day: u8, parts: &[Part], input: &str) -> Option<Result<Run, ParseError>> {
+    let run = match day {
+        1 => run::<d01::Day01>(parts, input),
+        2 => run::<d02::Day02>(parts, input),
+        3 => run::<d03::Day03>(parts, input),
+        4 => run::<d04::Day04>(parts, input),
+        5 => run::<d05::Day05>(parts, input),
+        6 => run::<d06::Day06>(parts, input),
+        7 => run::<d07::Day07>(parts, input),
+        8 => run::<d08::Day08>(parts, input),
+        9 => run::<d09::Day09>(parts, input),
+        10 => run::<d10::Day10>(parts, input),
+        11 => run::<d11::Day11>(parts, input),
+        12 => run::<d12::Day12>(parts, input),
+        13 => run::<d13::Day13>(parts, input),
+        14 => run::<d14::Day14>(parts, input),
+        15 => run::<d15::Day15>(parts, input),
+        _ => return None,
+    };
+
+    Some(run)
+}
+
+/// Solves each `(day, input)` pair like `solve`, returning the results in the
+/// same order. With the `parallel` feature the days run concurrently.
+pub fn solve_all(inputs: &[(u8, String)], parts: &[Part]) -> Vec<Option<Result<Run, ParseError>>> {
+    #[cfg(feature = "parallel")]
+    let inputs = inputs.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let inputs = inputs.iter();
+
+    inputs
+        .map(|(day, input)| solve(*day, parts, input))
+        .collect()
+}
+
+/// A random input for `day` drawn from `seed`, the size of a real one unless
+/// `size` overrides its main dimension, or `None` if the day has not been
+/// solved.
+pub fn generate(day: u8, seed: u64, size: Option<usize>) -> Option<String> {
+    fn seeded<G: Generator>(seed: u64, size: Option<usize>) -> String {
+        G::seeded(seed, size.unwrap_or(G::SIZE))
+    }
+
+    let input = match day {
+        1 => seeded::<d01::Day01>(seed, size),
+        2 => seeded::<d02::Day02>(seed, size),
+        3 => seeded::<d03::Day03>(seed, size),
+        4 => seeded::<d04::Day04>(seed, size),
+        5 => seeded::<d05::Day05>(seed, size),
+        6 => seeded::<d06::Day06>(seed, size),
+        7 => seeded::<d07::Day07>(seed, size),
+        8 => seeded::<d08::Day08>(seed, size),
+        9 => seeded::<d09::Day09>(seed, size),
+        10 => seeded::<d10::Day10>(seed, size),
+        11 => seeded::<d11::Day11>(seed, size),
+        12 => seeded::<d12::Day12>(seed, size),
+        13 => seeded::<d13::Day13>(seed, size),
+        14 => seeded::<d14::Day14>(seed, size),
+        15 => seeded::<d15::Day15>(seed, size),
+        _ => return None,
+    };
+
+    Some(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Allocations, ParseError, Part, PartRun, Run};
+
+    #[test]
+    fn solve_dispatches_to_day() {
+        let sample = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3";
+
+        let both = super::solve(1, &[Part::One, Part::Two], sample)
+            .unwrap()
+            .unwrap();
+        let two = super::solve(1, &[Part::Two], sample).unwrap().unwrap();
+
+        assert_eq!(1, both.day);
+        assert_eq!(vec!["11", "31"], both.answers().collect::<Vec<_>>());
+        assert_eq!(vec!["31"], two.answers().collect::<Vec<_>>());
+        assert_eq!(Part::Two, two.parts[0].part);
+    }
+
+    #[test]
+    fn run_serializes_timings_in_nanoseconds() {
+        let run = Run {
+            day: 7,
+            parse: Duration::from_micros(15),
+            parse_alloc: None,
+            parts: vec![PartRun {
+                part: Part::Two,
+                answer: "11387".to_string(),
+                time: Duration::from_millis(2),
+                alloc: Some(Allocations {
+                    count: 4,
+                    bytes: 96,
+                    peak: 64,
+                }),
+            }],
+        };
+
+        assert_eq!(
+            concat!(
+                r#"{"day":7,"parse_ns":15000,"parts":[{"part":2,"answer":"11387","time_ns":2000000,"#,
+                r#""alloc":{"count":4,"bytes":96,"peak":64}}]}"#
+            ),
+            serde_json::to_string(&run).unwrap()
+        );
+        assert_eq!(
+            run,
+            serde_json::from_str(&serde_json::to_string(&run).unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn solve_reports_parse_errors() {
+        let error = super::solve(1, &[Part::One], "3   4\n4   three")
+            .unwrap()
+            .unwrap_err();
+
+        assert_eq!(ParseError::new(2, 5, "three", "expected a number"), error);
+    }
+
+    #[test]
+    fn generated_inputs_solve() {
+        for day in super::DAYS {
+            let input = super::generate(day, 1, Some(20)).unwrap();
+
+            assert_eq!(Some(input.clone()), super::generate(day, 1, Some(20)));
+            assert!(super::solve(day, &[Part::One, Part::Two], &input)
+                .unwrap()
+                .is_ok());
+        }
+
+        assert_eq!(None, super::generate(20, 1, None));
+    }
+
+    #[test]
+    fn smallest_generated_inputs_solve() {
+        for day in super::DAYS {
+            for size in [0, 1] {
+                let input = super::generate(day, 2, Some(size)).unwrap();
+
+                assert!(
+                    super::solve(day, &[Part::One, Part::Two], &input)
+                        .unwrap()
+                        .is_ok(),
+                    "day {day} failed to parse its size {size} input"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn solve_all_matches_solve() {
+        let inputs = super::DAYS
+            .iter()
+            .map(|&day| (day, super::generate(day, 3, Some(30)).unwrap()))
+            .chain([(20, String::new())])
+            .collect::<Vec<_>>();
+        let parts = [Part::One, Part::Two];
+
+        let answers = |run: Option<Result<Run, ParseError>>| {
+            run.map(|run| {
+                run.unwrap()
+                    .answers()
+                    .map(str::to_owned)
+                    .collect::<Vec<_>>()
+            })
+        };
+        let serial = inputs
+            .iter()
+            .map(|(day, input)| answers(super::solve(*day, &parts, input)))
+            .collect::<Vec<_>>();
+        let all = super::solve_all(&inputs, &parts)
+            .into_iter()
+            .map(answers)
+            .collect::<Vec<_>>();
+
+        assert_eq!(serial, all);
+        assert_eq!(None, all[super::DAYS.len()]);
+    }
+
+    #[test]
+    fn solve_unknown_day() {
+        assert_eq!(None, super::solve(20, &[Part::One], ""));
+        assert_eq!(None, super::solve(26, &[Part::Two], ""));
+    }
+}
@@ -0,0 +1,517 @@
+use std::{path::PathBuf, process::ExitCode, time::Duration};
+
+use aoc::{
+    allocations::{self, Allocations},
+    answers::{Answers, Verdict},
+    answers_dir, answers_path,
+    client::Client,
+    config::Config,
+    fetch::{fetch_description, fetch_input, Download},
+    generate, input_path, inputs_dir,
+    regression::{accounts, Matrix},
+    scaffold::scaffold,
+    solve, solve_all,
+    submit::{submit, History},
+    watch::{report, run_day, DayWatcher},
+    workspace_root, Part, DAYS,
+};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use common::input::{
+    cache_dir, description_path, hash, read_input, read_stored_input, resolve, InputSource, YEAR,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum PartSelection {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    Both,
+}
+
+impl PartSelection {
+    fn parts(self) -> &'static [Part] {
+        match self {
+            PartSelection::One => &[Part::One],
+            PartSelection::Two => &[Part::Two],
+            PartSelection::Both => &[Part::One, Part::Two],
+        }
+    }
+}
+
+/// Run Advent of Code 2024 solutions
+#[derive(Debug, Parser)]
+#[command(name = "aoc", args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    run: RunArgs,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Download puzzle inputs and descriptions into the cache using `.aocdlconfig.shim`
+    Fetch {
+        /// Day to download; downloads every implemented day when omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+    },
+    /// Solve one part and post its answer using `.aocdlconfig.shim`
+    Submit {
+        /// Day to submit
+        #[arg(long)]
+        day: u8,
+
+        /// Part to submit
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Input file to read, or `-` for stdin
+        #[arg(long, env = "AOC_INPUT")]
+        input: Option<String>,
+    },
+    /// Check every part against the expected answers for its input
+    Verify {
+        /// Day to check; checks every implemented day when omitted
+        #[arg(long)]
+        day: Option<u8>,
+
+        /// Expected-answer registry; defaults to `answers.toml` in the workspace
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Check every day against every account's stored inputs and answers
+    Regress {
+        /// Day to check; checks every implemented day when omitted
+        #[arg(long)]
+        day: Option<u8>,
+
+        /// Directory of `<name>/dayNN.txt` inputs; defaults to `inputs` in the
+        /// workspace
+        #[arg(long)]
+        inputs: Option<PathBuf>,
+
+        /// Directory of `<name>.toml` answers; defaults to `answers` in the
+        /// workspace
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Re-run a day whenever its input, source or samples change
+    Watch {
+        /// Day to watch
+        #[arg(long)]
+        day: u8,
+
+        /// Input file to watch; defaults to the one the day's binary reads
+        #[arg(long, env = "AOC_INPUT")]
+        input: Option<PathBuf>,
+
+        /// Build the solution with optimisations
+        #[arg(long)]
+        release: bool,
+    },
+    /// Create the `DNN/Rust` crate for a new day and add it to the workspace
+    New {
+        /// Day to create
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Write random puzzle inputs for stress testing
+    Generate {
+        /// Day to generate; generates every implemented day when omitted
+        #[arg(long)]
+        day: Option<u8>,
+
+        /// Seed for the generator; the same seed always gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// Main dimension of the input, such as its number of lines or the
+        /// side of its grid; defaults to the size of a real input
+        #[arg(long, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+        size: Option<usize>,
+
+        /// Directory to write `dayNN.txt` files into
+        #[arg(long, default_value = "generated")]
+        dir: PathBuf,
+    },
+}
+
+#[derive(Debug, Args)]
+struct RunArgs {
+    /// Day to run; runs every implemented day when omitted
+    #[arg(long)]
+    day: Option<u8>,
+
+    /// Part to run
+    #[arg(long, value_enum, default_value_t = PartSelection::Both)]
+    part: PartSelection,
+
+    /// Input file to read, or `-` for stdin; requires `--day`, which also
+    /// reads `AOC_INPUT` when this is not given
+    #[arg(long, requires = "day")]
+    input: Option<String>,
+
+    /// Report how long parsing and each part took
+    #[arg(long)]
+    time: bool,
+
+    /// Report what parsing and each part allocated; needs the `alloc-stats`
+    /// feature
+    #[arg(long)]
+    alloc: bool,
+
+    /// Output format; JSON always includes timings, and allocations when
+    /// they are counted
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+fn run(args: RunArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let days = match args.day {
+        Some(day) if DAYS.contains(&day) => vec![day],
+        Some(day) => return Err(format!("day {day} is not implemented").into()),
+        None => DAYS.to_vec(),
+    };
+
+    if args.alloc && !allocations::ENABLED {
+        return Err("--alloc needs aoc built with `--features alloc-stats`".into());
+    }
+
+    let mut inputs = Vec::new();
+
+    for day in days {
+        // `AOC_INPUT` names a single input, so it only applies to `--day`.
+        // Without it, a day with no stored input is skipped rather than
+        // stopping the rest, and the note goes to stderr so that JSON output
+        // stays valid.
+        match args.day {
+            Some(_) => inputs.push((
+                day,
+                read_input(day, args.input.as_deref(), &[input_path(day)])?,
+            )),
+            None => match read_stored_input(day, &[input_path(day)]) {
+                Ok(data) => inputs.push((day, data)),
+                Err(err) => eprintln!("Day {day:02}: skipped ({err})"),
+            },
+        }
+    }
+
+    let mut runs = Vec::new();
+
+    for ((day, _), run) in inputs.iter().zip(solve_all(&inputs, args.part.parts())) {
+        let day = *day;
+        let run = run
+            .expect("day is implemented")
+            .map_err(|err| format!("failed to parse input for day {day}: {err}"))?;
+
+        if args.format == Format::Text {
+            let details = |time: Duration, alloc: Option<Allocations>| {
+                let time = args.time.then(|| format!("{time:.2?}"));
+                let alloc = alloc.filter(|_| args.alloc).map(|a| a.to_string());
+
+                time.into_iter().chain(alloc).collect::<Vec<_>>().join("; ")
+            };
+
+            if args.time || args.alloc {
+                println!(
+                    "Day {day:02} Parse: {}",
+                    details(run.parse, run.parse_alloc)
+                );
+            }
+
+            for part in &run.parts {
+                let details = details(part.time, part.alloc);
+
+                if details.is_empty() {
+                    println!("Day {day:02} Part {}: {}", part.part, part.answer);
+                } else {
+                    println!(
+                        "Day {day:02} Part {}: {} ({details})",
+                        part.part, part.answer
+                    );
+                }
+            }
+        }
+
+        runs.push(run);
+    }
+
+    if args.format == Format::Json {
+        println!("{}", serde_json::to_string_pretty(&runs)?);
+    }
+
+    Ok(())
+}
+
+fn fetch(day: Option<u8>) -> Result<(), Box<dyn std::error::Error>> {
+    let config = Config::locate()?;
+    let client = Client::from_config(&config);
+    let cache_dir = cache_dir().ok_or("no cache directory; set AOC_CACHE_DIR")?;
+
+    let days = day.map_or_else(|| DAYS.to_vec(), |day| vec![day]);
+
+    for day in days {
+        for download in [
+            fetch_input(&client, &cache_dir, config.year, day)?,
+            fetch_description(&client, &cache_dir, config.year, day)?,
+        ] {
+            match download {
+                Download::Cached(path) => println!("Day {day:02}: cached at {}", path.display()),
+                Download::Fetched(path) => println!("Day {day:02}: saved to {}", path.display()),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn submit_answer(day: u8, part: u8, input: Option<&str>) -> Result<(), Box<dyn std::error::Error>> {
+    if !DAYS.contains(&day) {
+        return Err(format!("day {day} is not implemented").into());
+    }
+
+    let part = Part::from_number(part).expect("part is validated by clap");
+    let data = read_input(day, input, &[input_path(day)])?;
+    let answer = solve(day, &[part], &data)
+        .expect("day is implemented")
+        .map_err(|err| format!("failed to parse input for day {day}: {err}"))?
+        .parts
+        .remove(0)
+        .answer;
+
+    let config = Config::locate()?;
+    let client = Client::from_config(&config);
+    let cache_dir = cache_dir().ok_or("no cache directory; set AOC_CACHE_DIR")?;
+    let mut history = History::load(&History::path(&cache_dir, config.year))?;
+
+    let outcome = submit(&client, &mut history, config.year, day, part, &answer)?;
+
+    println!("Day {day:02} Part {part}: {answer} is {outcome}");
+
+    Ok(())
+}
+
+fn verify(day: Option<u8>, answers: Option<PathBuf>) -> Result<(), Box<dyn std::error::Error>> {
+    let days = match day {
+        Some(day) if DAYS.contains(&day) => vec![day],
+        Some(day) => return Err(format!("day {day} is not implemented").into()),
+        None => DAYS.to_vec(),
+    };
+
+    // `AOC_INPUT` names a single input, so it only applies to a single day.
+    let single = days.len() == 1;
+    let answers = Answers::load(&answers.unwrap_or_else(answers_path))?;
+    let mut failures = 0;
+
+    for day in days {
+        let data = if single {
+            read_input(day, None, &[input_path(day)])
+        } else {
+            read_stored_input(day, &[input_path(day)])
+        };
+        let data = match data {
+            Ok(data) => data,
+            Err(err) => {
+                println!("Day {day:02}: skipped ({err})");
+                continue;
+            }
+        };
+
+        let input = hash(&data);
+        let parts = [Part::One, Part::Two];
+        let run = match solve(day, &parts, &data).expect("day is implemented") {
+            Ok(run) => run,
+            Err(err) => {
+                failures += 1;
+                println!("Day {day:02}: FAIL (failed to parse input: {err}) for input {input}");
+                continue;
+            }
+        };
+
+        for (part, answer) in parts.into_iter().zip(run.answers()) {
+            let verdict = answers.verify(day, part, &input, answer);
+            if let Verdict::Fail { .. } = verdict {
+                failures += 1;
+            }
+
+            println!("Day {day:02} Part {part}: {verdict} - got {answer} for input {input}");
+        }
+    }
+
+    if failures > 0 {
+        return Err(format!("{failures} answer(s) did not match").into());
+    }
+
+    Ok(())
+}
+
+fn regress(
+    day: Option<u8>,
+    inputs: Option<PathBuf>,
+    answers: Option<PathBuf>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let days = match day {
+        Some(day) if DAYS.contains(&day) => vec![day],
+        Some(day) => return Err(format!("day {day} is not implemented").into()),
+        None => DAYS.to_vec(),
+    };
+
+    let accounts = accounts(
+        &inputs.unwrap_or_else(inputs_dir),
+        &answers.unwrap_or_else(answers_dir),
+    )?;
+    if accounts.is_empty() {
+        return Err("no accounts found; add inputs as inputs/<name>/dayNN.txt".into());
+    }
+
+    let matrix = Matrix::run(&accounts, &days)?;
+    print!("{matrix}");
+
+    let failures = matrix.failures();
+    if !failures.is_empty() {
+        println!();
+        for failure in &failures {
+            println!("{failure}");
+        }
+        return Err(format!("{} answer(s) did not match", failures.len()).into());
+    }
+
+    Ok(())
+}
+
+fn watch(day: u8, input: Option<PathBuf>, release: bool) -> Result<(), Box<dyn std::error::Error>> {
+    if !DAYS.contains(&day) {
+        return Err(format!("day {day} is not implemented").into());
+    }
+
+    let explicit = input.map(InputSource::Path);
+    let input = match resolve(day, explicit, &[input_path(day)], cache_dir().as_deref())? {
+        InputSource::Path(path) => path,
+        InputSource::Stdin => return Err("cannot watch stdin; pass an input file".into()),
+    };
+    let root = workspace_root();
+    let crate_dir = root.join(format!("D{day:02}")).join("Rust");
+    // Cargo runs from the workspace root, so the watcher resolves the input
+    // to an absolute path for it.
+    let watcher = DayWatcher::new(&input, &crate_dir)?;
+    let input = watcher.input();
+
+    println!(
+        "Watching {} and {}",
+        input.display(),
+        watcher.crate_dir().display()
+    );
+
+    let mut previous = None;
+
+    loop {
+        match run_day(&root, day, input, release) {
+            Ok(run) => {
+                println!("{}", report(previous.as_ref(), &run));
+                previous = Some(run);
+            }
+            Err(err) => eprintln!("error: {err}"),
+        }
+
+        let Some(changed) = watcher.wait() else {
+            return Ok(());
+        };
+
+        println!();
+        for path in changed? {
+            println!("Changed: {}", path.display());
+        }
+    }
+}
+
+fn new_day(day: u8) -> Result<(), Box<dyn std::error::Error>> {
+    let description = cache_dir().map(|dir| description_path(&dir, YEAR, day));
+    let created = scaffold(&workspace_root(), day, description.as_deref())?;
+
+    println!("Day {day:02}: created {}", created.dir.display());
+    match (created.description, description) {
+        (Some(path), _) => println!("Day {day:02}: copied the description to {}", path.display()),
+        (None, Some(path)) => println!("Day {day:02}: no description cached at {}", path.display()),
+        (None, None) => println!("Day {day:02}: no cache directory to copy a description from"),
+    }
+    println!("Day {day:02}: add it to `DAYS`, `solve` and `generate` in aoc/src/lib.rs to run it from aoc");
+
+    Ok(())
+}
+
+fn generate_inputs(
+    day: Option<u8>,
+    seed: u64,
+    size: Option<usize>,
+    dir: PathBuf,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let days = match day {
+        Some(day) if DAYS.contains(&day) => vec![day],
+        Some(day) => return Err(format!("day {day} is not implemented").into()),
+        None => DAYS.to_vec(),
+    };
+
+    std::fs::create_dir_all(&dir)
+        .map_err(|err| format!("failed to create {}: {err}", dir.display()))?;
+
+    for day in days {
+        let input = generate(day, seed, size).expect("day is implemented");
+        let path = dir.join(format!("day{day:02}.txt"));
+
+        std::fs::write(&path, &input)
+            .map_err(|err| format!("failed to write {}: {err}", path.display()))?;
+
+        println!(
+            "Day {day:02}: wrote {} bytes to {}",
+            input.len(),
+            path.display()
+        );
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Some(Command::Fetch { day }) => fetch(day),
+        Some(Command::Submit { day, part, input }) => submit_answer(day, part, input.as_deref()),
+        Some(Command::Verify { day, answers }) => verify(day, answers),
+        Some(Command::Regress {
+            day,
+            inputs,
+            answers,
+        }) => regress(day, inputs, answers),
+        Some(Command::Watch {
+            day,
+            input,
+            release,
+        }) => watch(day, input, release),
+        Some(Command::New { day }) => new_day(day),
+        Some(Command::Generate {
+            day,
+            seed,
+            size,
+            dir,
+        }) => generate_inputs(day, seed, size, dir),
+        None => run(cli.run),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
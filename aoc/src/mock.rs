@@ -0,0 +1,126 @@
+//! Stand-in for the Advent of Code website so the HTTP paths can be tested
+//! without touching the network.
+
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::TcpListener,
+    sync::{Arc, Mutex},
+    thread::JoinHandle,
+};
+
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+pub struct MockServer {
+    url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl MockServer {
+    /// Serves one connection per scripted `(status, body)` response, in order.
+    pub fn start(responses: Vec<(u16, String)>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let recorded = Arc::clone(&requests);
+        let handle = std::thread::spawn(move || {
+            for (status, body) in responses {
+                let Ok((mut stream, _)) = listener.accept() else {
+                    return;
+                };
+
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let request = read_request(&mut reader);
+                recorded.lock().unwrap().push(request);
+
+                let response = format!(
+                    "HTTP/1.1 {status} Mock\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        Self {
+            url,
+            requests,
+            handle: Some(handle),
+        }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        // Only join once every scripted response has been served, otherwise
+        // the accept loop would block forever.
+        if let Some(handle) = self.handle.take() {
+            if handle.is_finished() {
+                handle.join().unwrap();
+            }
+        }
+    }
+}
+
+fn read_request(reader: &mut impl BufRead) -> Request {
+    let mut line = String::new();
+    reader.read_line(&mut line).unwrap();
+
+    let mut parts = line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_owned();
+    let path = parts.next().unwrap_or_default().to_owned();
+
+    let mut headers = Vec::new();
+    loop {
+        line.clear();
+        reader.read_line(&mut line).unwrap();
+
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+
+        if let Some((key, value)) = header.split_once(':') {
+            headers.push((key.trim().to_owned(), value.trim().to_owned()));
+        }
+    }
+
+    let length = headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, value)| value.parse().ok())
+        .unwrap_or(0);
+
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).unwrap();
+
+    Request {
+        method,
+        path,
+        headers,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    }
+}
@@ -0,0 +1,286 @@
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use common::{ParseError, Part};
+
+/// Days whose `DNN/Golang` program is a real solution. The D07, D08 and D09
+/// folders still hold an unmodified copy of the D01 program.
+pub const GO_DAYS: [u8; 5] = [1, 2, 3, 4, 5];
+
+#[derive(Debug)]
+pub enum ParityError {
+    Io {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    Build {
+        day: u8,
+        stderr: String,
+    },
+    Run {
+        day: u8,
+        stderr: String,
+    },
+    Parse {
+        day: u8,
+        error: ParseError,
+    },
+}
+
+impl Display for ParityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Io { path, error } => write!(f, "failed to access {}: {error}", path.display()),
+            Self::Build { day, stderr } => {
+                write!(
+                    f,
+                    "failed to build the Go program for day {day}: {}",
+                    stderr.trim()
+                )
+            }
+            Self::Run { day, stderr } => {
+                write!(f, "the Go program for day {day} failed: {}", stderr.trim())
+            }
+            Self::Parse { day, error } => {
+                write!(f, "failed to parse input for day {day}: {error}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParityError {}
+
+/// A part where the two implementations gave different answers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub day: u8,
+    pub part: Part,
+    pub rust: String,
+    pub go: String,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "Day {:02} Part {}: Rust says {} but Go says {}",
+            self.day, self.part, self.rust, self.go
+        )
+    }
+}
+
+/// Whether a `go` toolchain is on the `PATH`.
+pub fn go_available() -> bool {
+    Command::new("go")
+        .arg("version")
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+/// A compiled `DNN/Golang` program.
+#[derive(Debug, Clone)]
+pub struct GoProgram {
+    day: u8,
+    binary: PathBuf,
+}
+
+impl GoProgram {
+    pub fn new(day: u8, binary: PathBuf) -> Self {
+        Self { day, binary }
+    }
+
+    /// Builds the Go program for `day` into `out_dir`.
+    pub fn build(day: u8, out_dir: &Path) -> Result<Self, ParityError> {
+        let source = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(format!("D{day:02}"))
+            .join("Golang");
+        let binary = out_dir.join(format!("d{day:02}"));
+
+        let output = Command::new("go")
+            .arg("build")
+            .arg("-o")
+            .arg(&binary)
+            .arg(".")
+            .current_dir(&source)
+            .output()
+            .map_err(|error| ParityError::Io {
+                path: source.clone(),
+                error,
+            })?;
+
+        if !output.status.success() {
+            return Err(ParityError::Build {
+                day,
+                stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+            });
+        }
+
+        Ok(Self::new(day, binary))
+    }
+
+    /// Runs the program on `input` and returns the answer it printed for each
+    /// part. The Go programs read `../input.txt`, so they run from a directory
+    /// inside `scratch` with the input written next to it.
+    pub fn run(&self, input: &str, scratch: &Path) -> Result<Vec<String>, ParityError> {
+        let work = scratch.join("run");
+        let io_error = |path: &Path| {
+            let path = path.to_owned();
+            move |error| ParityError::Io { path, error }
+        };
+
+        std::fs::create_dir_all(&work).map_err(io_error(&work))?;
+
+        let input_path = scratch.join("input.txt");
+        std::fs::write(&input_path, input).map_err(io_error(&input_path))?;
+
+        let output = Command::new(&self.binary)
+            .current_dir(&work)
+            .output()
+            .map_err(io_error(&self.binary))?;
+
+        if !output.status.success() {
+            return Err(ParityError::Run {
+                day: self.day,
+                stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+            });
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(|line| line.trim().to_owned())
+            .filter(|line| !line.is_empty())
+            .collect())
+    }
+}
+
+/// Solves `input` with both implementations and returns every part where
+/// they disagree.
+///
+/// Trailing newlines are stripped first: the Go programs split on `\n` and
+/// index into every resulting line, including the empty one after the last
+/// newline.
+pub fn compare(
+    program: &GoProgram,
+    input: &str,
+    scratch: &Path,
+) -> Result<Vec<Mismatch>, ParityError> {
+    let day = program.day;
+    let input = input.trim_end();
+    let parts = [Part::One, Part::Two];
+
+    let rust = crate::solve(day, &parts, input)
+        .expect("Go days are implemented in Rust")
+        .map_err(|error| ParityError::Parse { day, error })?;
+    let go = program.run(input, scratch)?;
+
+    Ok(parts
+        .into_iter()
+        .zip(rust.answers())
+        .enumerate()
+        .filter_map(|(idx, (part, rust))| {
+            let go = go.get(idx).map_or("<missing>", String::as_str);
+
+            (rust != go).then(|| Mismatch {
+                day,
+                part,
+                rust: rust.to_owned(),
+                go: go.to_owned(),
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{input::read_stored_input, Part};
+
+    use super::{compare, go_available, GoProgram, Mismatch, GO_DAYS};
+    use crate::{generate, input_path, scratch::scratch_dir};
+
+    static SAMPLES: [(u8, &str); 5] = [
+        (1, "3   4\n4   3\n2   5\n1   3\n3   9\n3   3"),
+        (
+            2,
+            "7 6 4 2 1\n1 2 7 8 9\n9 7 6 2 1\n1 3 2 4 5\n8 6 4 4 1\n1 3 6 7 9",
+        ),
+        (
+            3,
+            "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))",
+        ),
+        (
+            4,
+            "MMMSXXMASM\nMSAMXMSMSA\nAMXSXMAAMM\nMSAMASMSMX\nXMASAMXAMM\n\
+             XXAMMXXAMA\nSMSMSASXSS\nSAXAMASAAA\nMAMMMXMMMM\nMXMXAXMASX",
+        ),
+        (
+            5,
+            "47|53\n97|13\n97|61\n97|47\n75|29\n61|13\n75|53\n29|13\n97|29\n53|29\n\
+             61|53\n97|53\n61|29\n47|13\n75|47\n97|75\n47|61\n75|61\n47|29\n75|13\n\
+             53|13\n\n75,47,61,53,29\n97,61,53,29,13\n75,29,13\n75,97,47,61,53\n\
+             61,13,29\n97,13,75,29,47",
+        ),
+    ];
+
+    #[test]
+    fn run_reads_input_from_parent_directory() {
+        let scratch = scratch_dir("parity-fake");
+        let script = scratch.join("fake-go");
+        std::fs::write(&script, "#!/bin/sh\nwc -l < ../input.txt\necho 31\n").unwrap();
+        std::process::Command::new("chmod")
+            .arg("+x")
+            .arg(&script)
+            .status()
+            .unwrap();
+
+        let program = GoProgram::new(1, script);
+        let mismatches = compare(&program, SAMPLES[0].1, &scratch).unwrap();
+
+        assert_eq!(
+            vec![Mismatch {
+                day: 1,
+                part: Part::One,
+                rust: "11".to_owned(),
+                go: "5".to_owned(),
+            }],
+            mismatches
+        );
+
+        std::fs::remove_dir_all(scratch).unwrap();
+    }
+
+    #[test]
+    #[ignore = "needs go"]
+    fn rust_matches_go() {
+        assert!(go_available(), "no Go toolchain on the PATH");
+
+        let scratch = scratch_dir("parity-go");
+        let mut mismatches = Vec::new();
+
+        for day in GO_DAYS {
+            let program = GoProgram::build(day, &scratch).unwrap();
+            let sample = SAMPLES.iter().find(|(d, _)| *d == day).unwrap().1;
+
+            let mut inputs = vec![("sample".to_owned(), sample.to_owned())];
+            if let Ok(real) = read_stored_input(day, &[input_path(day)]) {
+                inputs.push(("real".to_owned(), real));
+            }
+            for seed in 1..=3 {
+                let input = generate(day, seed, None).expect("Go days are implemented in Rust");
+                inputs.push((format!("seed {seed}"), input));
+            }
+
+            for (name, input) in inputs {
+                let found = compare(&program, &input, &scratch).unwrap();
+                mismatches.extend(found.into_iter().map(|m| format!("{m} ({name} input)")));
+            }
+        }
+
+        assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+
+        std::fs::remove_dir_all(scratch).unwrap();
+    }
+}
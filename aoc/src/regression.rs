@@ -0,0 +1,305 @@
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use common::{input::hash, Part};
+
+use crate::answers::{Answers, AnswersError, Verdict};
+
+/// One person's puzzle inputs, read from `inputs/<name>/dayNN.txt`, and the
+/// answers accepted for them, read from `answers/<name>.toml`.
+#[derive(Debug)]
+pub struct Account {
+    pub name: String,
+    pub inputs: PathBuf,
+    pub answers: Answers,
+}
+
+impl Account {
+    pub fn input_path(&self, day: u8) -> PathBuf {
+        self.inputs.join(format!("day{day:02}.txt"))
+    }
+}
+
+#[derive(Debug)]
+pub enum RegressionError {
+    Io {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    Answers(AnswersError),
+}
+
+impl Display for RegressionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Io { path, error } => write!(f, "failed to read {}: {error}", path.display()),
+            Self::Answers(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for RegressionError {}
+
+impl From<AnswersError> for RegressionError {
+    fn from(error: AnswersError) -> Self {
+        Self::Answers(error)
+    }
+}
+
+/// Every account with a directory under `inputs_dir`, sorted by name.
+pub fn accounts(inputs_dir: &Path, answers_dir: &Path) -> Result<Vec<Account>, RegressionError> {
+    let io_error = |error| RegressionError::Io {
+        path: inputs_dir.to_owned(),
+        error,
+    };
+
+    let mut accounts = Vec::new();
+
+    for entry in std::fs::read_dir(inputs_dir).map_err(io_error)? {
+        let entry = entry.map_err(io_error)?;
+        if !entry.path().is_dir() {
+            continue;
+        }
+
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let answers = Answers::load_account(&answers_dir.join(format!("{name}.toml")))?;
+
+        accounts.push(Account {
+            name,
+            inputs: entry.path(),
+            answers,
+        });
+    }
+
+    accounts.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(accounts)
+}
+
+/// Outcome of one part of one day on one account's input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Cell {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    /// The part was solved but no answer is recorded for it.
+    Unknown {
+        actual: String,
+    },
+    /// The account has no input for this day.
+    Missing,
+    /// The input did not parse.
+    Error(String),
+}
+
+impl Cell {
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Pass => "pass",
+            Self::Fail { .. } => "FAIL",
+            Self::Unknown { .. } => "?",
+            Self::Missing => "-",
+            Self::Error(_) => "ERROR",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub day: u8,
+    pub part: Part,
+    /// One cell per account, in the matrix's account order.
+    pub cells: Vec<Cell>,
+}
+
+/// Pass/fail results of every day and part against every account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix {
+    pub accounts: Vec<String>,
+    pub rows: Vec<Row>,
+}
+
+impl Matrix {
+    /// Solves each of `days` once per account and checks both parts.
+    pub fn run(accounts: &[Account], days: &[u8]) -> Result<Self, RegressionError> {
+        let parts = [Part::One, Part::Two];
+        let mut rows = days
+            .iter()
+            .flat_map(|&day| {
+                parts.map(|part| Row {
+                    day,
+                    part,
+                    cells: Vec::new(),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for account in accounts {
+            for (&day, rows) in days.iter().zip(rows.chunks_mut(parts.len())) {
+                let path = account.input_path(day);
+                let data = match std::fs::read_to_string(&path) {
+                    Ok(data) => data,
+                    Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                        rows.iter_mut()
+                            .for_each(|row| row.cells.push(Cell::Missing));
+                        continue;
+                    }
+                    Err(error) => return Err(RegressionError::Io { path, error }),
+                };
+
+                let run = match crate::solve(day, &parts, &data) {
+                    Some(Ok(run)) => run,
+                    Some(Err(error)) => {
+                        let cell = Cell::Error(error.to_string());
+                        rows.iter_mut().for_each(|row| row.cells.push(cell.clone()));
+                        continue;
+                    }
+                    None => {
+                        rows.iter_mut()
+                            .for_each(|row| row.cells.push(Cell::Missing));
+                        continue;
+                    }
+                };
+
+                let input = hash(&data);
+                for (row, actual) in rows.iter_mut().zip(run.answers()) {
+                    let cell = match account.answers.verify(day, row.part, &input, actual) {
+                        Verdict::Pass => Cell::Pass,
+                        Verdict::Fail { expected } => Cell::Fail {
+                            expected,
+                            actual: actual.to_owned(),
+                        },
+                        Verdict::Unknown => Cell::Unknown {
+                            actual: actual.to_owned(),
+                        },
+                    };
+                    row.cells.push(cell);
+                }
+            }
+        }
+
+        Ok(Self {
+            accounts: accounts.iter().map(|a| a.name.clone()).collect(),
+            rows,
+        })
+    }
+
+    /// A line for each failing cell, naming the account, day and part.
+    pub fn failures(&self) -> Vec<String> {
+        let mut failures = Vec::new();
+
+        for row in &self.rows {
+            for (account, cell) in self.accounts.iter().zip(&row.cells) {
+                let detail = match cell {
+                    Cell::Fail { expected, actual } => format!("expected {expected}, got {actual}"),
+                    Cell::Error(error) => format!("failed to parse input: {error}"),
+                    _ => continue,
+                };
+
+                failures.push(format!(
+                    "{account}: Day {:02} Part {}: {detail}",
+                    row.day, row.part
+                ));
+            }
+        }
+
+        failures
+    }
+}
+
+impl Display for Matrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let label = "Day 00 Part 0".len();
+        let widths = self
+            .accounts
+            .iter()
+            .map(|name| name.len().max("ERROR".len()))
+            .collect::<Vec<_>>();
+
+        write!(f, "{:label$}", "")?;
+        for (name, width) in self.accounts.iter().zip(&widths) {
+            write!(f, "  {name:width$}")?;
+        }
+        writeln!(f)?;
+
+        for row in &self.rows {
+            write!(f, "Day {:02} Part {}", row.day, row.part)?;
+            for (cell, width) in row.cells.iter().zip(&widths) {
+                write!(f, "  {:width$}", cell.label())?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use common::Part;
+
+    use super::{accounts, Cell, Matrix};
+    use crate::scratch::scratch_dir;
+
+    fn write(path: PathBuf, contents: &str) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, contents).unwrap();
+    }
+
+    #[test]
+    fn matrix_checks_every_account() {
+        let root = scratch_dir("regress-matrix");
+        let (inputs, answers) = (root.join("inputs"), root.join("answers"));
+
+        write(
+            inputs.join("alice/day01.txt"),
+            "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n",
+        );
+        write(
+            answers.join("alice.toml"),
+            "[[answer]]\nday = 1\npart = 1\nanswer = \"11\"\n\n\
+             [[answer]]\nday = 1\npart = 2\nanswer = \"30\"\n",
+        );
+        write(inputs.join("bob/day01.txt"), "1   2\nthree   4\n");
+        write(inputs.join("bob/day11.txt"), "125 17\n");
+
+        let accounts = accounts(&inputs, &answers).unwrap();
+        let matrix = Matrix::run(&accounts, &[1, 11]).unwrap();
+
+        assert_eq!(vec!["alice", "bob"], matrix.accounts);
+        assert_eq!((1, Part::Two), (matrix.rows[1].day, matrix.rows[1].part));
+        assert_eq!(Cell::Pass, matrix.rows[0].cells[0]);
+        assert_eq!(
+            Cell::Fail {
+                expected: "30".to_owned(),
+                actual: "31".to_owned()
+            },
+            matrix.rows[1].cells[0]
+        );
+        assert!(matches!(matrix.rows[0].cells[1], Cell::Error(_)));
+        assert_eq!(Cell::Missing, matrix.rows[2].cells[0]);
+        assert_eq!(
+            Cell::Unknown {
+                actual: "55312".to_owned()
+            },
+            matrix.rows[2].cells[1]
+        );
+
+        assert_eq!(3, matrix.failures().len());
+        assert_eq!(
+            "               alice  bob  \n\
+             Day 01 Part 1  pass   ERROR\n\
+             Day 01 Part 2  FAIL   ERROR\n\
+             Day 11 Part 1  -      ?    \n\
+             Day 11 Part 2  -      ?    \n",
+            matrix.to_string()
+        );
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,249 @@
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+/// Files of a new day crate, relative to its `DNN/Rust` directory, with
+/// `{DD}` standing for the zero-padded day and `{DAY}` for the bare one.
+const TEMPLATES: [(&str, &str); 5] = [
+    ("Cargo.toml", include_str!("../templates/Cargo.toml.tmpl")),
+    ("src/lib.rs", include_str!("../templates/lib.rs.tmpl")),
+    ("src/main.rs", include_str!("../templates/main.rs.tmpl")),
+    (
+        "src/generate.rs",
+        include_str!("../templates/generate.rs.tmpl"),
+    ),
+    (
+        "benches/bench.rs",
+        include_str!("../templates/bench.rs.tmpl"),
+    ),
+];
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    Exists(PathBuf),
+    /// The workspace manifest has no `members = [...]` list to add to.
+    NoMembers(PathBuf),
+    Io {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Exists(path) => write!(f, "{} already exists", path.display()),
+            Self::NoMembers(path) => {
+                write!(f, "{} has no workspace members list", path.display())
+            }
+            Self::Io { path, error } => write!(f, "failed to access {}: {error}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+/// What `scaffold` created.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scaffold {
+    /// The new `DNN/Rust` crate directory.
+    pub dir: PathBuf,
+    /// Where the cached description was copied to, if there was one.
+    pub description: Option<PathBuf>,
+}
+
+/// Creates the `DNN/Rust` crate for `day` under the workspace `root`, adds it
+/// to the workspace members and copies in the puzzle description from
+/// `description` when that file exists.
+pub fn scaffold(
+    root: &Path,
+    day: u8,
+    description: Option<&Path>,
+) -> Result<Scaffold, ScaffoldError> {
+    let member = format!("D{day:02}/Rust");
+    let dir = root.join(&member);
+    let manifest = root.join("Cargo.toml");
+
+    let io_error = |path: &Path| {
+        let path = path.to_owned();
+        move |error| ScaffoldError::Io { path, error }
+    };
+
+    if dir.exists() {
+        return Err(ScaffoldError::Exists(dir));
+    }
+
+    // Check the manifest before writing anything so a failure leaves the
+    // workspace untouched.
+    let workspace = std::fs::read_to_string(&manifest).map_err(io_error(&manifest))?;
+    let workspace = add_member(&workspace, &member)
+        .ok_or_else(|| ScaffoldError::NoMembers(manifest.clone()))?;
+
+    // Remove whatever was created if a later step fails, so that running it
+    // again does not stop at `Exists`.
+    let created = match dir.parent().filter(|parent| !parent.exists()) {
+        Some(parent) => parent.to_owned(),
+        None => dir.clone(),
+    };
+
+    let description = write_crate(&dir, day, description).and_then(|description| {
+        std::fs::write(&manifest, workspace).map_err(io_error(&manifest))?;
+        Ok(description)
+    });
+    if description.is_err() {
+        let _ = std::fs::remove_dir_all(&created);
+    }
+
+    Ok(Scaffold {
+        dir,
+        description: description?,
+    })
+}
+
+/// Writes the templates for `day` into `dir` and copies in `description`,
+/// returning where the description went.
+fn write_crate(
+    dir: &Path,
+    day: u8,
+    description: Option<&Path>,
+) -> Result<Option<PathBuf>, ScaffoldError> {
+    let io_error = |path: &Path| {
+        let path = path.to_owned();
+        move |error| ScaffoldError::Io { path, error }
+    };
+
+    for (file, template) in TEMPLATES {
+        let path = dir.join(file);
+        let contents = template
+            .replace("{DD}", &format!("{day:02}"))
+            .replace("{DAY}", &day.to_string());
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(io_error(parent))?;
+        }
+        std::fs::write(&path, contents).map_err(io_error(&path))?;
+    }
+
+    match description.filter(|path| path.is_file()) {
+        Some(source) => {
+            let path = dir.join("desc.txt");
+            std::fs::copy(source, &path).map_err(io_error(source))?;
+            Ok(Some(path))
+        }
+        None => Ok(None),
+    }
+}
+
+/// `manifest` with `member` added to its `members` list, after the last
+/// member that sorts before it, or `None` if there is no such list.
+fn add_member(manifest: &str, member: &str) -> Option<String> {
+    let entry = format!("\"{member}\",");
+    let mut lines = manifest.lines().collect::<Vec<_>>();
+
+    let start = lines
+        .iter()
+        .position(|line| line.trim_start().starts_with("members = ["))?;
+    let end = start + lines[start..].iter().position(|line| line.trim() == "]")?;
+
+    if lines[start + 1..end]
+        .iter()
+        .any(|line| line.trim() == entry)
+    {
+        return Some(manifest.to_owned());
+    }
+
+    let at = lines[start + 1..end]
+        .iter()
+        .rposition(|line| line.trim() < entry.as_str())
+        .map_or(start + 1, |idx| start + idx + 2);
+
+    let indented = format!("    {entry}");
+    lines.insert(at, &indented);
+
+    Some(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{add_member, scaffold, ScaffoldError};
+    use crate::scratch::scratch_dir;
+
+    static MANIFEST: &str = "[workspace]\nresolver = \"2\"\nmembers = [\n    \"aoc\",\n    \
+                             \"D09/Rust\",\n    \"D11/Rust\",\n]\nexclude = [\"fuzz\"]\n";
+
+    #[test]
+    fn member_is_inserted_in_order() {
+        let added = add_member(MANIFEST, "D10/Rust").unwrap();
+
+        assert!(added.contains("    \"D09/Rust\",\n    \"D10/Rust\",\n    \"D11/Rust\",\n"));
+        assert_eq!(added, add_member(&added, "D10/Rust").unwrap());
+        assert!(add_member(MANIFEST, "D12/Rust")
+            .unwrap()
+            .contains("\"D11/Rust\",\n    \"D12/Rust\",\n]"));
+        assert_eq!(None, add_member("[package]\nname = \"x\"\n", "D10/Rust"));
+    }
+
+    #[test]
+    fn scaffold_creates_crate() {
+        let root = scratch_dir("new-crate");
+        std::fs::write(root.join("Cargo.toml"), MANIFEST).unwrap();
+        let description = root.join("day12.desc.txt");
+        std::fs::write(&description, "--- Day 12: Garden Groups ---\n").unwrap();
+
+        let created = scaffold(&root, 12, Some(&description)).unwrap();
+
+        assert_eq!(root.join("D12/Rust"), created.dir);
+        assert_eq!(Some(created.dir.join("desc.txt")), created.description);
+
+        let lib = std::fs::read_to_string(created.dir.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day12;") && lib.contains("const DAY: u8 = 12;"));
+        let cargo = std::fs::read_to_string(created.dir.join("Cargo.toml")).unwrap();
+        assert!(cargo.contains("name = \"d12\""));
+        assert!(created.dir.join("benches/bench.rs").is_file());
+        assert!(std::fs::read_to_string(root.join("Cargo.toml"))
+            .unwrap()
+            .contains("\"D12/Rust\""));
+
+        let again = scaffold(&root, 12, None).unwrap_err();
+        assert!(matches!(again, ScaffoldError::Exists(dir) if dir == created.dir));
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn scaffold_without_description() {
+        let root = scratch_dir("new-bare");
+        std::fs::write(root.join("Cargo.toml"), MANIFEST).unwrap();
+
+        let created = scaffold(&root, 10, Some(&root.join("missing.txt"))).unwrap();
+
+        assert_eq!(None, created.description);
+        assert!(!created.dir.join("desc.txt").exists());
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    // Reading `/proc/self/mem` from the start fails, so copying it as the
+    // description fails after the templates are written.
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn failed_scaffold_is_removed() {
+        let root = scratch_dir("new-failed");
+        std::fs::write(root.join("Cargo.toml"), MANIFEST).unwrap();
+
+        let error = scaffold(&root, 12, Some("/proc/self/mem".as_ref())).unwrap_err();
+
+        assert!(matches!(error, ScaffoldError::Io { .. }));
+        assert!(!root.join("D12").exists());
+        assert_eq!(
+            MANIFEST,
+            std::fs::read_to_string(root.join("Cargo.toml")).unwrap()
+        );
+
+        let created = scaffold(&root, 12, None).unwrap();
+        assert!(created.dir.join("src/lib.rs").is_file());
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
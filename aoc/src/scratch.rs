@@ -0,0 +1,12 @@
+//! Per-test directories under the system temp directory.
+
+use std::path::PathBuf;
+
+/// Creates an empty directory for the test `name`, replacing whatever an
+/// earlier run left there. Tests remove it again once they pass.
+pub fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
@@ -0,0 +1,391 @@
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use common::Part;
+use serde::{Deserialize, Serialize};
+
+use crate::client::{Client, ClientError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    RateLimited,
+    /// The site is not expecting an answer for this part, either because it
+    /// is already solved or because the part before it is not yet.
+    #[serde(alias = "already-solved")]
+    WrongLevel,
+}
+
+impl Outcome {
+    /// Classifies the article the website sends back after an answer is posted.
+    pub fn parse(body: &str) -> Option<Self> {
+        if body.contains("That's the right answer") {
+            Some(Self::Correct)
+        } else if body.contains("You gave an answer too recently") {
+            Some(Self::RateLimited)
+        } else if body.contains("You don't seem to be solving the right level") {
+            Some(Self::WrongLevel)
+        } else if body.contains("That's not the right answer") {
+            if body.contains("your answer is too high") {
+                Some(Self::TooHigh)
+            } else if body.contains("your answer is too low") {
+                Some(Self::TooLow)
+            } else {
+                Some(Self::Incorrect)
+            }
+        } else {
+            None
+        }
+    }
+
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Self::TooHigh | Self::TooLow | Self::Incorrect)
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::TooHigh => write!(f, "too high"),
+            Self::TooLow => write!(f, "too low"),
+            Self::Incorrect => write!(f, "incorrect"),
+            Self::RateLimited => write!(f, "rate limited"),
+            Self::WrongLevel => write!(f, "not the right level"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+impl Submission {
+    /// Whether this wrong submission already rules `answer` out, either because
+    /// it is the same answer or because it lies beyond a too-high/too-low bound.
+    fn rules_out(&self, answer: &str) -> bool {
+        if !self.outcome.is_wrong() {
+            return false;
+        }
+
+        if self.answer == answer {
+            return true;
+        }
+
+        match (self.answer.parse::<i128>(), answer.parse::<i128>()) {
+            (Ok(bound), Ok(value)) => match self.outcome {
+                Outcome::TooHigh => value >= bound,
+                Outcome::TooLow => value <= bound,
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    KnownWrong(Submission),
+    /// The history shows an answer for the part was accepted, so the site
+    /// would only answer that it is the wrong level.
+    Solved(Submission),
+    UnknownResponse(String),
+    Client(ClientError),
+    Io {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    Parse {
+        path: PathBuf,
+        error: serde_json::Error,
+    },
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::KnownWrong(previous) => write!(
+                f,
+                "refusing to submit: {} was already {} for day {} part {}",
+                previous.answer, previous.outcome, previous.day, previous.part
+            ),
+            Self::Solved(previous) => write!(
+                f,
+                "refusing to submit: day {} part {} is already solved ({} was {})",
+                previous.day, previous.part, previous.answer, previous.outcome
+            ),
+            Self::UnknownResponse(body) => {
+                write!(f, "could not understand the response: {}", body.trim())
+            }
+            Self::Client(error) => write!(f, "{error}"),
+            Self::Io { path, error } => write!(f, "failed to access {}: {error}", path.display()),
+            Self::Parse { path, error } => {
+                write!(f, "failed to parse {}: {error}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+impl From<ClientError> for SubmitError {
+    fn from(error: ClientError) -> Self {
+        Self::Client(error)
+    }
+}
+
+/// Every answer submitted so far, persisted as JSON.
+#[derive(Debug)]
+pub struct History {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+impl History {
+    /// Location of the history file inside the cache directory.
+    pub fn path(cache_dir: &Path, year: u16) -> PathBuf {
+        cache_dir.join(year.to_string()).join("submissions.json")
+    }
+
+    pub fn load(path: &Path) -> Result<Self, SubmitError> {
+        let submissions = match std::fs::read_to_string(path) {
+            Ok(source) => serde_json::from_str(&source).map_err(|error| SubmitError::Parse {
+                path: path.to_owned(),
+                error,
+            })?,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(error) => {
+                return Err(SubmitError::Io {
+                    path: path.to_owned(),
+                    error,
+                })
+            }
+        };
+
+        Ok(Self {
+            path: path.to_owned(),
+            submissions,
+        })
+    }
+
+    pub fn submissions(&self) -> &[Submission] {
+        &self.submissions
+    }
+
+    /// Returns the recorded wrong submission that rules out `answer`, if any.
+    pub fn known_wrong(&self, day: u8, part: Part, answer: &str) -> Option<&Submission> {
+        self.submissions
+            .iter()
+            .filter(|s| s.day == day && s.part == part.number())
+            .find(|s| s.rules_out(answer))
+    }
+
+    /// Returns the recorded correct submission for the part, if any.
+    pub fn solved(&self, day: u8, part: Part) -> Option<&Submission> {
+        self.submissions
+            .iter()
+            .find(|s| s.day == day && s.part == part.number() && s.outcome == Outcome::Correct)
+    }
+
+    fn record(&mut self, submission: Submission) -> Result<(), SubmitError> {
+        self.submissions.push(submission);
+
+        let io_error = |error| SubmitError::Io {
+            path: self.path.clone(),
+            error,
+        };
+
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).map_err(io_error)?;
+        }
+
+        let json = serde_json::to_string_pretty(&self.submissions).expect("submissions serialize");
+        std::fs::write(&self.path, json).map_err(io_error)
+    }
+}
+
+/// Posts `answer` unless the history already rules it out or shows the part
+/// is solved, then records the outcome.
+pub fn submit(
+    client: &Client,
+    history: &mut History,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> Result<Outcome, SubmitError> {
+    if let Some(previous) = history.solved(day, part) {
+        return Err(SubmitError::Solved(previous.clone()));
+    }
+    if let Some(previous) = history.known_wrong(day, part, answer) {
+        return Err(SubmitError::KnownWrong(previous.clone()));
+    }
+
+    let body = client.answer(year, day, part.number(), answer)?;
+    let outcome = Outcome::parse(&body).ok_or(SubmitError::UnknownResponse(body))?;
+
+    history.record(Submission {
+        day,
+        part: part.number(),
+        answer: answer.to_owned(),
+        outcome,
+    })?;
+
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use common::Part;
+
+    use super::{submit, History, Outcome, SubmitError};
+    use crate::{client::Client, mock::MockServer, scratch::scratch_dir};
+
+    static CORRECT: &str = "<article><p>That's the right answer! You are one gold star closer to finding the Chief Historian.</p></article>";
+    static TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high. If you're stuck, make sure you're using the full input data.</p></article>";
+    static TOO_LOW: &str =
+        "<article><p>That's not the right answer; your answer is too low.</p></article>";
+    static WRONG: &str = "<article><p>That's not the right answer. If you're stuck, make sure you're using the full input data.</p></article>";
+    static RATE_LIMITED: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 41s left to wait.</p></article>";
+    static SOLVED: &str = "<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>";
+
+    fn empty_history(dir: &Path) -> History {
+        History::load(&History::path(dir, 2024)).unwrap()
+    }
+
+    #[test]
+    fn parse_outcomes() {
+        assert_eq!(Some(Outcome::Correct), Outcome::parse(CORRECT));
+        assert_eq!(Some(Outcome::TooHigh), Outcome::parse(TOO_HIGH));
+        assert_eq!(Some(Outcome::TooLow), Outcome::parse(TOO_LOW));
+        assert_eq!(Some(Outcome::Incorrect), Outcome::parse(WRONG));
+        assert_eq!(Some(Outcome::RateLimited), Outcome::parse(RATE_LIMITED));
+        assert_eq!(Some(Outcome::WrongLevel), Outcome::parse(SOLVED));
+        assert_eq!(
+            Outcome::WrongLevel,
+            serde_json::from_str::<Outcome>("\"already-solved\"").unwrap()
+        );
+        assert_eq!(None, Outcome::parse("<html>Please log in</html>"));
+    }
+
+    #[test]
+    fn submit_posts_and_records() {
+        let server = MockServer::start(vec![(200, TOO_LOW.to_owned()), (200, CORRECT.to_owned())]);
+        let client = Client::new(server.url(), "abc123");
+        let dir = scratch_dir("submit-record");
+        let mut history = empty_history(&dir);
+
+        let first = submit(&client, &mut history, 2024, 7, Part::Two, "100").unwrap();
+        let second = submit(&client, &mut history, 2024, 7, Part::Two, "250").unwrap();
+
+        assert_eq!(Outcome::TooLow, first);
+        assert_eq!(Outcome::Correct, second);
+
+        let requests = server.requests();
+        assert_eq!(2, requests.len());
+        assert_eq!("POST", requests[0].method);
+        assert_eq!("/2024/day/7/answer", requests[0].path);
+        assert_eq!("level=2&answer=100", requests[0].body);
+        assert_eq!(Some("session=abc123"), requests[0].header("cookie"));
+
+        let reloaded = History::load(&history.path).unwrap();
+        assert_eq!(history.submissions(), reloaded.submissions());
+        assert_eq!(2, reloaded.submissions().len());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let server = MockServer::start(vec![(200, TOO_HIGH.to_owned()), (200, WRONG.to_owned())]);
+        let client = Client::new(server.url(), "abc123");
+        let dir = scratch_dir("submit-refuse");
+        let mut history = empty_history(&dir);
+
+        submit(&client, &mut history, 2024, 1, Part::One, "5000").unwrap();
+        submit(&client, &mut history, 2024, 1, Part::One, "4000").unwrap();
+
+        let same = submit(&client, &mut history, 2024, 1, Part::One, "4000").unwrap_err();
+        let higher = submit(&client, &mut history, 2024, 1, Part::One, "6000").unwrap_err();
+
+        assert!(matches!(same, SubmitError::KnownWrong(ref s) if s.answer == "4000"));
+        assert!(matches!(higher, SubmitError::KnownWrong(ref s) if s.answer == "5000"));
+        assert!(history.known_wrong(1, Part::Two, "4000").is_none());
+        assert_eq!(2, server.requests().len());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn rate_limited_answers_can_be_retried() {
+        let server = MockServer::start(vec![
+            (200, RATE_LIMITED.to_owned()),
+            (200, CORRECT.to_owned()),
+        ]);
+        let client = Client::new(server.url(), "abc123");
+        let dir = scratch_dir("submit-retry");
+        let mut history = empty_history(&dir);
+
+        let first = submit(&client, &mut history, 2024, 2, Part::One, "42").unwrap();
+        let second = submit(&client, &mut history, 2024, 2, Part::One, "42").unwrap();
+
+        assert_eq!(Outcome::RateLimited, first);
+        assert_eq!(Outcome::Correct, second);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn refuses_solved_parts() {
+        let server = MockServer::start(vec![(200, CORRECT.to_owned())]);
+        let client = Client::new(server.url(), "abc123");
+        let dir = scratch_dir("submit-solved");
+        let mut history = empty_history(&dir);
+
+        submit(&client, &mut history, 2024, 3, Part::One, "161").unwrap();
+
+        let again = submit(&client, &mut history, 2024, 3, Part::One, "161").unwrap_err();
+        let other = submit(&client, &mut history, 2024, 3, Part::One, "170").unwrap_err();
+
+        assert!(matches!(again, SubmitError::Solved(ref s) if s.answer == "161"));
+        assert!(matches!(other, SubmitError::Solved(ref s) if s.answer == "161"));
+        assert!(history.solved(3, Part::Two).is_none());
+        assert_eq!(1, server.requests().len());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn part_two_before_part_one_can_be_retried() {
+        let server = MockServer::start(vec![
+            (200, SOLVED.to_owned()),
+            (200, CORRECT.to_owned()),
+            (200, CORRECT.to_owned()),
+        ]);
+        let client = Client::new(server.url(), "abc123");
+        let dir = scratch_dir("submit-level");
+        let mut history = empty_history(&dir);
+
+        let early = submit(&client, &mut history, 2024, 3, Part::Two, "48").unwrap();
+        submit(&client, &mut history, 2024, 3, Part::One, "161").unwrap();
+        let later = submit(&client, &mut history, 2024, 3, Part::Two, "48").unwrap();
+
+        assert_eq!(Outcome::WrongLevel, early);
+        assert_eq!(Outcome::Correct, later);
+        assert_eq!(3, server.requests().len());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,303 @@
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+    process::Command,
+    sync::mpsc::{channel, Receiver, RecvTimeoutError},
+    time::Duration,
+};
+
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::Run;
+
+/// How long to wait for more changes after the first one, so that an editor
+/// saving several files triggers a single run.
+const SETTLE: Duration = Duration::from_millis(200);
+
+#[derive(Debug)]
+pub enum WatchError {
+    Notify(notify::Error),
+    /// A watched path could not be resolved to an absolute one.
+    Path {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    Io(std::io::Error),
+    /// `cargo` failed to build or run the solution.
+    Run(String),
+    Output(serde_json::Error),
+}
+
+impl Display for WatchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Notify(error) => write!(f, "failed to watch for changes: {error}"),
+            Self::Path { path, error } => {
+                write!(f, "failed to resolve {}: {error}", path.display())
+            }
+            Self::Io(error) => write!(f, "failed to start cargo: {error}"),
+            Self::Run(stderr) => write!(f, "{}", stderr.trim_end()),
+            Self::Output(error) => write!(f, "failed to read the runner's output: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for WatchError {}
+
+impl From<notify::Error> for WatchError {
+    fn from(error: notify::Error) -> Self {
+        Self::Notify(error)
+    }
+}
+
+/// Watches a day's input file and crate directory, which holds its source,
+/// samples and description.
+pub struct DayWatcher {
+    // Dropping the watcher stops the notifications, so it lives alongside
+    // the channel it feeds.
+    _watcher: RecommendedWatcher,
+    events: Receiver<notify::Result<Event>>,
+    input: PathBuf,
+    crate_dir: PathBuf,
+}
+
+impl DayWatcher {
+    /// Watches `input` and `crate_dir`, resolved to absolute paths so that
+    /// they compare equal to the paths in notifications.
+    pub fn new(input: &Path, crate_dir: &Path) -> Result<Self, WatchError> {
+        let input = canonicalize(input)?;
+        let crate_dir = canonicalize(crate_dir)?;
+
+        let (sender, events) = channel();
+        let mut watcher = notify::recommended_watcher(sender)?;
+
+        // Editors often save by replacing the file, which would end a watch
+        // on the file itself, so watch the directory holding it instead.
+        let input_dir = input.parent().unwrap_or(Path::new("/"));
+        watcher.watch(input_dir, RecursiveMode::NonRecursive)?;
+        watcher.watch(&crate_dir, RecursiveMode::Recursive)?;
+
+        Ok(Self {
+            _watcher: watcher,
+            events,
+            input,
+            crate_dir,
+        })
+    }
+
+    /// The absolute path of the watched input, safe to hand to a runner in
+    /// another directory.
+    pub fn input(&self) -> &Path {
+        &self.input
+    }
+
+    pub fn crate_dir(&self) -> &Path {
+        &self.crate_dir
+    }
+
+    /// Blocks until a watched file changes and returns the changed paths, or
+    /// `None` once notifications stop.
+    pub fn wait(&self) -> Option<Result<Vec<PathBuf>, WatchError>> {
+        let mut changed = Vec::new();
+
+        loop {
+            let event = if changed.is_empty() {
+                self.events.recv().ok()?
+            } else {
+                match self.events.recv_timeout(SETTLE) {
+                    Ok(event) => event,
+                    Err(RecvTimeoutError::Timeout) => return Some(Ok(changed)),
+                    Err(RecvTimeoutError::Disconnected) => return None,
+                }
+            };
+
+            let event = match event {
+                Ok(event) => event,
+                Err(error) => return Some(Err(error.into())),
+            };
+
+            if !matches!(
+                event.kind,
+                EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+            ) {
+                continue;
+            }
+
+            for path in event.paths {
+                if self.is_relevant(&path) && !changed.contains(&path) {
+                    changed.push(path);
+                }
+            }
+        }
+    }
+
+    fn is_relevant(&self, path: &Path) -> bool {
+        let in_target = path.components().any(|c| c.as_os_str() == "target");
+        let editor_file = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with('.') || name.ends_with('~'));
+
+        path == self.input || (path.starts_with(&self.crate_dir) && !in_target && !editor_file)
+    }
+}
+
+fn canonicalize(path: &Path) -> Result<PathBuf, WatchError> {
+    std::fs::canonicalize(path).map_err(|error| WatchError::Path {
+        path: path.to_owned(),
+        error,
+    })
+}
+
+/// Builds and runs the current source of `day` on `input` through `cargo`,
+/// so that source changes are picked up as well as input changes.
+pub fn run_day(root: &Path, day: u8, input: &Path, release: bool) -> Result<Run, WatchError> {
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+
+    let mut command = Command::new(cargo);
+    command
+        .current_dir(root)
+        .args(["run", "--quiet", "-p", "aoc"]);
+    if release {
+        command.arg("--release");
+    }
+    command
+        .args([
+            "--",
+            "--day",
+            &day.to_string(),
+            "--format",
+            "json",
+            "--input",
+        ])
+        .arg(input);
+
+    let output = command.output().map_err(WatchError::Io)?;
+    if !output.status.success() {
+        return Err(WatchError::Run(
+            String::from_utf8_lossy(&output.stderr).into_owned(),
+        ));
+    }
+
+    let mut runs: Vec<Run> = serde_json::from_slice(&output.stdout).map_err(WatchError::Output)?;
+    runs.pop()
+        .ok_or_else(|| WatchError::Run("the runner printed no results".to_owned()))
+}
+
+/// The answers and timings of `current`, noting every answer that changed
+/// and how the timings compare with `previous`.
+pub fn report(previous: Option<&Run>, current: &Run) -> String {
+    let day = current.day;
+    let mut lines = vec![format!(
+        "Day {day:02} Parse: {}",
+        timing(previous.map(|p| p.parse), current.parse)
+    )];
+
+    for part in &current.parts {
+        let before = previous.and_then(|p| p.parts.iter().find(|b| b.part == part.part));
+
+        let answer = match before {
+            Some(before) if before.answer != part.answer => {
+                format!("{} (was {})", part.answer, before.answer)
+            }
+            _ => part.answer.clone(),
+        };
+
+        lines.push(format!(
+            "Day {day:02} Part {}: {answer} in {}",
+            part.part,
+            timing(before.map(|b| b.time), part.time)
+        ));
+    }
+
+    lines.join("\n")
+}
+
+fn timing(before: Option<Duration>, now: Duration) -> String {
+    match before {
+        Some(before) if !before.is_zero() => {
+            let change = (now.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0;
+            format!("{now:.2?} ({change:+.0}% from {before:.2?})")
+        }
+        _ => format!("{now:.2?}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        path::{Component, PathBuf},
+        time::Duration,
+    };
+
+    use super::{report, DayWatcher};
+    use crate::{scratch::scratch_dir, Part, PartRun, Run};
+
+    fn run(answers: [&str; 2], millis: [u64; 2]) -> Run {
+        Run {
+            day: 6,
+            parse: Duration::from_millis(1),
+            parse_alloc: None,
+            parts: [Part::One, Part::Two]
+                .into_iter()
+                .zip(answers.into_iter().zip(millis))
+                .map(|(part, (answer, millis))| PartRun {
+                    part,
+                    answer: answer.to_owned(),
+                    time: Duration::from_millis(millis),
+                    alloc: None,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn first_report_has_no_diff() {
+        assert_eq!(
+            "Day 06 Parse: 1.00ms\nDay 06 Part 1: 41 in 2.00ms\nDay 06 Part 2: 6 in 8.00ms",
+            report(None, &run(["41", "6"], [2, 8]))
+        );
+    }
+
+    #[test]
+    fn report_diffs_answers_and_timings() {
+        let previous = run(["41", "5"], [4, 8]);
+        let current = run(["41", "6"], [2, 10]);
+
+        assert_eq!(
+            "Day 06 Parse: 1.00ms (+0% from 1.00ms)\n\
+             Day 06 Part 1: 41 in 2.00ms (-50% from 4.00ms)\n\
+             Day 06 Part 2: 6 (was 5) in 10.00ms (+25% from 8.00ms)",
+            report(Some(&previous), &current)
+        );
+    }
+
+    #[test]
+    fn relative_input_is_resolved() {
+        let dir = scratch_dir("watch");
+        let crate_dir = dir.join("Rust");
+        std::fs::create_dir_all(&crate_dir).unwrap();
+        std::fs::write(dir.join("input.txt"), "").unwrap();
+
+        // The same file, reached from the current directory by `..` steps.
+        let cwd = std::env::current_dir().unwrap();
+        let up = cwd
+            .components()
+            .filter(|c| matches!(c, Component::Normal(_)));
+        let relative = up
+            .map(|_| PathBuf::from(".."))
+            .collect::<PathBuf>()
+            .join(dir.strip_prefix("/").unwrap())
+            .join("input.txt");
+        assert!(relative.is_relative());
+
+        let watcher = DayWatcher::new(&relative, &crate_dir).unwrap();
+        let input = std::fs::canonicalize(dir.join("input.txt")).unwrap();
+
+        assert_eq!(input, watcher.input());
+        assert!(watcher.is_relevant(&input));
+        assert!(watcher.is_relevant(&watcher.crate_dir().join("src/lib.rs")));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,42 @@
+use std::{hint::black_box, path::PathBuf};
+
+use criterion::Criterion;
+
+use crate::{
+    generate::Generator,
+    input::{cache_dir, resolve},
+    Solution,
+};
+
+/// Benchmarks parsing and each part of `S` on `input` as the `dayNN` group.
+pub fn bench_solution<S: Solution>(c: &mut Criterion, input: &str) {
+    let parsed = S::parse(input).expect("benchmark input must parse");
+
+    let mut group = c.benchmark_group(format!("day{:02}", S::DAY));
+
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(input))));
+    group.bench_function("part_one", |b| b.iter(|| S::part_one(black_box(&parsed))));
+    group.bench_function("part_two", |b| b.iter(|| S::part_two(black_box(&parsed))));
+
+    group.finish();
+}
+
+/// The real puzzle input for `day`, looked up like the day binaries do, or
+/// the output of `generate` when no input is available.
+pub fn bench_input(day: u8, defaults: &[PathBuf], generate: impl FnOnce() -> String) -> String {
+    let source = resolve(day, None, defaults, cache_dir().as_deref());
+
+    match source.and_then(|source| source.read()) {
+        Ok(input) => input,
+        Err(_) => {
+            eprintln!("day {day:02}: no puzzle input found, benchmarking a generated one");
+            generate()
+        }
+    }
+}
+
+/// A generated input the size of a real one, always drawn from the same seed
+/// so that runs stay comparable.
+pub fn generated<G: Generator>() -> String {
+    G::seeded(0, G::SIZE)
+}
@@ -0,0 +1,199 @@
+use crate::{Part, Solution};
+
+/// A run of example lines lifted out of the puzzle prose, such as a sample
+/// input or one of the diagrams that walk through it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block {
+    /// Part whose text the block appears in.
+    pub part: Part,
+    /// Line of the description the block starts on, counting from 1.
+    pub line: usize,
+    pub text: String,
+}
+
+/// A puzzle description stored as plain text, as in a day's `desc.txt`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Description {
+    /// The `Day N: Title` heading, when the description starts with one.
+    pub title: Option<String>,
+    pub blocks: Vec<Block>,
+    /// The answer each part states for its example, in part order.
+    pub answers: Vec<(Part, String)>,
+}
+
+impl Description {
+    /// Splits `text` into prose and example blocks.
+    ///
+    /// The stored descriptions lose the page's formatting, so lines are told
+    /// apart by shape: a line with two or more words is prose and any other
+    /// non-blank line belongs to an example. A part's stated answer is taken
+    /// to be the last number in its last prose line that has one, skipping
+    /// the closing question.
+    pub fn parse(text: &str) -> Self {
+        let mut description = Self::default();
+        let mut part = Part::One;
+        let mut answer = None;
+        let mut block: Option<Block> = None;
+
+        for (idx, line) in text.lines().enumerate() {
+            let heading = line.trim().trim_matches('-').trim();
+
+            if idx == 0 && line.starts_with("---") && heading.starts_with("Day ") {
+                description.title = Some(heading.to_owned());
+                continue;
+            }
+
+            if line.starts_with("---") && heading == "Part Two" {
+                description.blocks.extend(block.take());
+                description.answers.extend(answer.take().map(|a| (part, a)));
+                part = Part::Two;
+                continue;
+            }
+
+            if line.trim().is_empty() || is_prose(line) {
+                description.blocks.extend(block.take());
+
+                if !line.contains('?') {
+                    if let Some(number) = last_number(line) {
+                        answer = Some(number.to_owned());
+                    }
+                }
+                continue;
+            }
+
+            match &mut block {
+                Some(block) => {
+                    block.text.push('\n');
+                    block.text.push_str(line);
+                }
+                None => {
+                    block = Some(Block {
+                        part,
+                        line: idx + 1,
+                        text: line.to_owned(),
+                    })
+                }
+            }
+        }
+
+        description.blocks.extend(block);
+        description.answers.extend(answer.map(|a| (part, a)));
+        description
+    }
+
+    /// The first example block, which is the sample puzzle input.
+    pub fn sample(&self) -> Option<&str> {
+        self.blocks.first().map(|block| block.text.as_str())
+    }
+
+    pub fn answer(&self, part: Part) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|(p, _)| *p == part)
+            .map(|(_, answer)| answer.as_str())
+    }
+}
+
+fn is_prose(line: &str) -> bool {
+    line.split_whitespace()
+        .filter(|token| {
+            let word = token.trim_matches(|c: char| c.is_ascii_punctuation());
+            !word.is_empty() && word.chars().all(|c| c.is_alphabetic() || c == '\'')
+        })
+        .nth(1)
+        .is_some()
+}
+
+fn last_number(line: &str) -> Option<&str> {
+    line.split(|c: char| !c.is_ascii_digit())
+        .rfind(|run| !run.is_empty())
+}
+
+/// Solves the sample in `description` with `S` and asserts that every part
+/// matches the answer the description states for it. Returns the parts that
+/// were checked, and panics if the description has no sample or answers.
+pub fn assert_examples<S: Solution>(description: &str) -> Vec<Part> {
+    let description = Description::parse(description);
+
+    let sample = description
+        .sample()
+        .expect("description should contain an example");
+    assert!(
+        !description.answers.is_empty(),
+        "description should state an answer"
+    );
+
+    let input = S::parse(sample).unwrap_or_else(|error| {
+        panic!("day {} failed to parse its sample: {error}", S::DAY);
+    });
+
+    description
+        .answers
+        .iter()
+        .map(|(part, expected)| {
+            let actual = S::solve(&input, *part).to_string();
+            assert_eq!(
+                expected,
+                &actual,
+                "day {} part {part} disagrees with the description",
+                S::DAY
+            );
+            *part
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Block, Description};
+    use crate::Part;
+
+    static DESCRIPTION: &str = "--- Day 0: Counting ---\n\
+        You count the numbers in a list (your puzzle input). For example:\n\
+        \n\
+        3 4\n\
+        1 2\n\
+        Counting them one by one:\n\
+        1\n\
+        2\n\
+        In total, there are 4 numbers in the list.\n\
+        \n\
+        How many numbers are there in 17 lists?\n\
+        \n\
+        --- Part Two ---\n\
+        Now add them up. The example list sums to 10.\n";
+
+    #[test]
+    fn parse_blocks_and_answers() {
+        let description = Description::parse(DESCRIPTION);
+
+        assert_eq!(Some("Day 0: Counting"), description.title.as_deref());
+        assert_eq!(
+            vec![
+                Block {
+                    part: Part::One,
+                    line: 4,
+                    text: "3 4\n1 2".to_owned()
+                },
+                Block {
+                    part: Part::One,
+                    line: 7,
+                    text: "1\n2".to_owned()
+                },
+            ],
+            description.blocks
+        );
+        assert_eq!(Some("3 4\n1 2"), description.sample());
+        assert_eq!(Some("4"), description.answer(Part::One));
+        assert_eq!(Some("10"), description.answer(Part::Two));
+    }
+
+    #[test]
+    fn parse_without_examples() {
+        let description = Description::parse("Nothing to see here.\n");
+
+        assert_eq!(None, description.title);
+        assert_eq!(None, description.sample());
+        assert!(description.answers.is_empty());
+    }
+}
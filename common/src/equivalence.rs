@@ -0,0 +1,58 @@
+use std::fmt::Debug;
+
+use proptest::{
+    strategy::Strategy,
+    test_runner::{Config, TestCaseError, TestRunner},
+};
+
+/// Runs `reference` and `candidate` on random inputs drawn from `inputs` and
+/// panics if they ever disagree, reporting the smallest disagreeing input
+/// proptest can shrink to.
+///
+/// Meant for days with two implementations of the same thing, such as a fast
+/// solver checked against a naive one. The number of cases follows proptest's
+/// usual `PROPTEST_CASES` setting.
+pub fn assert_equivalent<S, T>(
+    inputs: S,
+    reference: impl Fn(&S::Value) -> T,
+    candidate: impl Fn(&S::Value) -> T,
+) where
+    S: Strategy,
+    S::Value: Debug,
+    T: PartialEq + Debug,
+{
+    let mut runner = TestRunner::new(Config::default());
+
+    let result = runner.run(&inputs, |input| {
+        let expected = reference(&input);
+        let actual = candidate(&input);
+
+        if expected == actual {
+            Ok(())
+        } else {
+            Err(TestCaseError::fail(format!(
+                "reference gave {expected:?} but candidate gave {actual:?}"
+            )))
+        }
+    });
+
+    if let Err(error) = result {
+        panic!("{error}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::assert_equivalent;
+
+    #[test]
+    fn equivalent_implementations_pass() {
+        assert_equivalent(0..1000u32, |n| n * 2, |n| n + n);
+    }
+
+    #[test]
+    #[should_panic(expected = "minimal failing input: 10")]
+    fn disagreement_reports_smallest_input() {
+        assert_equivalent(0..1000u32, |&n| n.min(10), |&n| n.min(9));
+    }
+}
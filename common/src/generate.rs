@@ -0,0 +1,112 @@
+use std::ops::RangeInclusive;
+
+/// Small xorshift64* generator. It has no dependencies so that a seed keeps
+/// producing the same puzzle input across toolchains and crate upgrades.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // SplitMix64 scrambles the seed so that small and zero seeds still
+        // start from a well-mixed, non-zero state.
+        let mut z = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+        Self((z ^ (z >> 31)) | 1)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// A value in `0..bound`; `bound` must not be zero.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "bound must be positive");
+        self.next_u64() % bound
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        start + self.below(end - start + 1)
+    }
+
+    /// An index into a collection of `len` items.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// `true` with probability `percent` in 100.
+    pub fn percent(&mut self, percent: u64) -> bool {
+        self.below(100) < percent
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+/// Produces random but valid puzzle inputs for one day.
+pub trait Generator {
+    /// Size and shape of the generated input.
+    type Config;
+
+    /// Main dimension of a real puzzle input, such as its number of lines or
+    /// the side of its grid.
+    const SIZE: usize;
+
+    /// A configuration whose main dimension is `size`.
+    fn config(size: usize) -> Self::Config;
+
+    fn generate(rng: &mut Rng, config: &Self::Config) -> String;
+
+    /// An input of the given `size` drawn from a fresh generator for `seed`.
+    fn seeded(seed: u64, size: usize) -> String {
+        Self::generate(&mut Rng::new(seed), &Self::config(size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Rng;
+
+    #[test]
+    fn same_seed_same_sequence() {
+        let first = (0..8).map({
+            let mut rng = Rng::new(7);
+            move |_| rng.next_u64()
+        });
+        let second = (0..8).map({
+            let mut rng = Rng::new(7);
+            move |_| rng.next_u64()
+        });
+
+        assert!(first.eq(second));
+        assert_ne!(Rng::new(7).next_u64(), Rng::new(8).next_u64());
+        assert_ne!(0, Rng::new(0).next_u64());
+    }
+
+    #[test]
+    fn values_stay_in_range() {
+        let mut rng = Rng::new(1);
+        let mut items = [1, 2, 3, 4, 5];
+
+        for _ in 0..1000 {
+            assert!((10..=20).contains(&rng.range(10..=20)));
+            assert!(rng.below(3) < 3);
+        }
+
+        rng.shuffle(&mut items);
+        items.sort_unstable();
+        assert_eq!([1, 2, 3, 4, 5], items);
+    }
+}
@@ -0,0 +1,273 @@
+use std::{
+    fmt::Display,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+/// Puzzle year used for the input cache layout.
+pub const YEAR: u16 = 2024;
+
+/// Environment variable holding an explicit input path (or `-` for stdin).
+pub const INPUT_VAR: &str = "AOC_INPUT";
+
+/// Environment variable overriding the input cache directory.
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    Path(PathBuf),
+}
+
+impl InputSource {
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            Self::Stdin
+        } else {
+            Self::Path(PathBuf::from(arg))
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            Self::Stdin => {
+                let mut data = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut data)
+                    .map_err(|error| InputError::Io {
+                        source: self.clone(),
+                        error,
+                    })?;
+                Ok(data)
+            }
+            Self::Path(path) => std::fs::read_to_string(path).map_err(|error| InputError::Io {
+                source: self.clone(),
+                error,
+            }),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Stdin => write!(f, "<stdin>"),
+            Self::Path(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound {
+        day: u8,
+        tried: Vec<PathBuf>,
+    },
+    Io {
+        source: InputSource,
+        error: std::io::Error,
+    },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::NotFound { day, tried } => {
+                write!(f, "no input found for day {day}; tried ")?;
+                for (idx, path) in tried.iter().enumerate() {
+                    if idx > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", path.display())?;
+                }
+                Ok(())
+            }
+            Self::Io { source, error } => write!(f, "failed to read {source}: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Stable FNV-1a hash of an input, ignoring trailing whitespace, used to key
+/// expected answers to the input they belong to.
+pub fn hash(input: &str) -> String {
+    let hash = input
+        .trim_end()
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+
+    format!("{hash:016x}")
+}
+
+/// Location of a day's input inside the cache directory.
+pub fn cache_path(cache_dir: &Path, year: u16, day: u8) -> PathBuf {
+    cache_dir
+        .join(year.to_string())
+        .join(format!("day{day:02}.txt"))
+}
+
+/// Location of a day's puzzle description inside the cache directory, copied
+/// into new day crates by `aoc new`.
+pub fn description_path(cache_dir: &Path, year: u16, day: u8) -> PathBuf {
+    cache_dir
+        .join(year.to_string())
+        .join(format!("day{day:02}.desc.txt"))
+}
+
+/// Cache directory from `AOC_CACHE_DIR`, falling back to the user cache
+/// directory (`$XDG_CACHE_HOME/aoc` or `$HOME/.cache/aoc`).
+pub fn cache_dir() -> Option<PathBuf> {
+    if let Some(dir) = std::env::var_os(CACHE_DIR_VAR) {
+        return Some(PathBuf::from(dir));
+    }
+
+    std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))
+        .map(|dir| dir.join("aoc"))
+}
+
+/// Picks the input for `day`: an explicit source wins, otherwise the first
+/// existing path out of `defaults` and then the cache directory.
+pub fn resolve(
+    day: u8,
+    explicit: Option<InputSource>,
+    defaults: &[PathBuf],
+    cache_dir: Option<&Path>,
+) -> Result<InputSource, InputError> {
+    if let Some(source) = explicit {
+        return Ok(source);
+    }
+
+    let tried = defaults
+        .iter()
+        .cloned()
+        .chain(cache_dir.map(|dir| cache_path(dir, YEAR, day)))
+        .collect::<Vec<_>>();
+
+    match tried.iter().find(|path| path.is_file()) {
+        Some(path) => Ok(InputSource::Path(path.clone())),
+        None => Err(InputError::NotFound { day, tried }),
+    }
+}
+
+/// Reads the input for `day`, taking an explicit path from `arg` or the
+/// `AOC_INPUT` environment variable before searching `defaults` and the cache.
+pub fn read_input(day: u8, arg: Option<&str>, defaults: &[PathBuf]) -> Result<String, InputError> {
+    let explicit = match arg {
+        Some(arg) => Some(InputSource::from_arg(arg)),
+        None => std::env::var(INPUT_VAR)
+            .ok()
+            .map(|arg| InputSource::from_arg(&arg)),
+    };
+
+    resolve(day, explicit, defaults, cache_dir().as_deref())?.read()
+}
+
+/// Reads the input for `day` from `defaults` or the cache alone, ignoring
+/// `AOC_INPUT`, for commands that cover several days and so cannot share one
+/// explicit input.
+pub fn read_stored_input(day: u8, defaults: &[PathBuf]) -> Result<String, InputError> {
+    resolve(day, None, defaults, cache_dir().as_deref())?.read()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::{
+        cache_path, description_path, hash, read_stored_input, resolve, InputError, InputSource,
+        INPUT_VAR,
+    };
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-input-{}-{name}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn from_arg_dash_is_stdin() {
+        assert_eq!(InputSource::Stdin, InputSource::from_arg("-"));
+        assert_eq!(
+            InputSource::Path(PathBuf::from("input.txt")),
+            InputSource::from_arg("input.txt")
+        );
+    }
+
+    #[test]
+    fn hash_ignores_trailing_whitespace() {
+        assert_eq!("cbf29ce484222325", hash(""));
+        assert_eq!(hash("125 17"), hash("125 17\n"));
+        assert_ne!(hash("125 17"), hash("125 18"));
+    }
+
+    #[test]
+    fn cache_path_layout() {
+        assert_eq!(
+            Path::new("/cache/2024/day07.txt"),
+            cache_path(Path::new("/cache"), 2024, 7)
+        );
+        assert_eq!(
+            Path::new("/cache/2024/day07.desc.txt"),
+            description_path(Path::new("/cache"), 2024, 7)
+        );
+    }
+
+    #[test]
+    fn explicit_source_wins() {
+        let explicit = InputSource::Path(PathBuf::from("missing.txt"));
+        let actual = resolve(1, Some(explicit.clone()), &[], None).unwrap();
+
+        assert_eq!(explicit, actual);
+    }
+
+    #[test]
+    fn falls_back_to_cache() {
+        let dir = scratch_dir("cache");
+        let cached = cache_path(&dir, 2024, 3);
+        std::fs::create_dir_all(cached.parent().unwrap()).unwrap();
+        std::fs::write(&cached, "mul(2,3)").unwrap();
+
+        let actual = resolve(3, None, &[dir.join("missing.txt")], Some(&dir)).unwrap();
+
+        assert_eq!(InputSource::Path(cached), actual);
+        assert_eq!("mul(2,3)", actual.read().unwrap());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn not_found_names_every_path() {
+        let dir = scratch_dir("missing");
+        let default = dir.join("input.txt");
+
+        let error = resolve(5, None, std::slice::from_ref(&default), Some(&dir)).unwrap_err();
+
+        assert!(matches!(&error, InputError::NotFound { day: 5, tried } if tried.len() == 2));
+        let message = error.to_string();
+        assert!(message.contains(&default.display().to_string()));
+        assert!(message.contains(&cache_path(&dir, 2024, 5).display().to_string()));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn stored_input_ignores_env() {
+        let dir = scratch_dir("stored");
+        let (default, other) = (dir.join("input.txt"), dir.join("other.txt"));
+        std::fs::write(&default, "125 17").unwrap();
+        std::fs::write(&other, "0 1 10").unwrap();
+
+        std::env::set_var(INPUT_VAR, &other);
+        let actual = read_stored_input(11, std::slice::from_ref(&default));
+        std::env::remove_var(INPUT_VAR);
+
+        assert_eq!("125 17", actual.unwrap());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,111 @@
+#[cfg(feature = "bench")]
+pub mod bench;
+pub mod description;
+#[cfg(feature = "equivalence")]
+pub mod equivalence;
+pub mod generate;
+pub mod input;
+pub mod parse;
+
+use std::fmt::{Debug, Display};
+
+pub use parse::ParseError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn from_number(number: u8) -> Option<Self> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// A single day's puzzle, split into parsing and the two parts so that
+/// generic tooling can drive (and time) each stage separately.
+pub trait Solution {
+    /// Day of the month this puzzle was released on.
+    const DAY: u8;
+
+    /// Parsed puzzle input shared by both parts.
+    type Input;
+
+    /// Answer produced by both parts.
+    type Answer: Debug + Display + PartialEq;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_one(input: &Self::Input) -> Self::Answer;
+
+    fn part_two(input: &Self::Input) -> Self::Answer;
+
+    fn solve(input: &Self::Input, part: Part) -> Self::Answer {
+        match part {
+            Part::One => Self::part_one(input),
+            Part::Two => Self::part_two(input),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse::parse_number, ParseError, Part, Solution};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+
+        type Input = Vec<u32>;
+        type Answer = u32;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            input
+                .split_whitespace()
+                .map(|x| parse_number(input, x))
+                .collect()
+        }
+
+        fn part_one(input: &Self::Input) -> Self::Answer {
+            input.iter().sum()
+        }
+
+        fn part_two(input: &Self::Input) -> Self::Answer {
+            input.iter().product()
+        }
+    }
+
+    #[test]
+    fn solve_selects_part() {
+        let input = Sum::parse("2 3 4").unwrap();
+
+        assert_eq!(9, Sum::solve(&input, Part::One));
+        assert_eq!(24, Sum::solve(&input, Part::Two));
+    }
+
+    #[test]
+    fn parse_error_points_at_token() {
+        let error = Sum::parse("2 3x 4").unwrap_err();
+
+        assert_eq!(ParseError::new(1, 3, "3x", "expected a number"), error);
+    }
+}
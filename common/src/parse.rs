@@ -0,0 +1,96 @@
+use std::{fmt::Display, str::FromStr};
+
+/// Malformed puzzle input, pointing at the offending text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// One-based line number.
+    pub line: usize,
+    /// One-based character column within the line.
+    pub column: usize,
+    /// The text that could not be parsed.
+    pub text: String,
+    pub reason: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: &str, reason: &str) -> Self {
+        Self {
+            line,
+            column,
+            text: text.to_owned(),
+            reason: reason.to_owned(),
+        }
+    }
+
+    /// An error for `token`, which must be a slice of `line`, on the first
+    /// line; parsers working line by line move it with [`ParseError::on_line`].
+    pub fn token(line: &str, token: &str, reason: &str) -> Self {
+        Self::new(1, column_of(line, token), token, reason)
+    }
+
+    /// Moves the error to the given one-based line, keeping the column.
+    pub fn on_line(self, line: usize) -> Self {
+        Self { line, ..self }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {} (found `{}`)",
+            self.line, self.column, self.reason, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// One-based character column of `token` inside `line`, or just past the end
+/// of `line` if `token` is not a slice of it.
+pub fn column_of(line: &str, token: &str) -> usize {
+    let start = line.as_ptr() as usize;
+    let offset = (token.as_ptr() as usize).wrapping_sub(start);
+
+    let byte = if offset <= line.len() && line.is_char_boundary(offset) {
+        offset
+    } else {
+        line.len()
+    };
+
+    line[..byte].chars().count() + 1
+}
+
+/// Parses `token`, a slice of `line`, as a number.
+pub fn parse_number<T: FromStr>(line: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::token(line, token, "expected a number"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{column_of, parse_number, ParseError};
+
+    #[test]
+    fn column_of_token() {
+        let line = "12   x4";
+
+        assert_eq!(1, column_of(line, &line[..2]));
+        assert_eq!(6, column_of(line, &line[5..]));
+        assert_eq!(8, column_of(line, &line[7..]));
+        assert_eq!(8, column_of(line, "elsewhere"));
+    }
+
+    #[test]
+    fn parse_number_reports_token() {
+        let line = "3   x4";
+        let error = parse_number::<i32>(line, &line[4..]).unwrap_err();
+
+        assert_eq!(ParseError::new(1, 5, "x4", "expected a number"), error);
+        assert_eq!(
+            "line 7, column 5: expected a number (found `x4`)",
+            error.on_line(7).to_string()
+        );
+    }
+}
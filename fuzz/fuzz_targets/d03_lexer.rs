@@ -0,0 +1,13 @@
+#![no_main]
+
+use d03::lexer::Lexer;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(memory) = std::str::from_utf8(data) {
+        Lexer::new(memory).for_each(drop);
+
+        d03::solve_part_one(memory);
+        d03::solve_part_two(memory);
+    }
+});
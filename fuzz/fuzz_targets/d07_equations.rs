@@ -0,0 +1,25 @@
+#![no_main]
+
+use common::Solution;
+use d07::Day07;
+use libfuzzer_sys::fuzz_target;
+
+/// Checking an equation tries every combination of operators, so longer ones
+/// only make each run slower without reaching new code.
+const MAX_OPERANDS: usize = 12;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    if let Ok(equations) = Day07::parse(input) {
+        if equations
+            .iter()
+            .all(|(_, values)| values.len() <= MAX_OPERANDS)
+        {
+            Day07::part_one(&equations);
+            Day07::part_two(&equations);
+        }
+    }
+});
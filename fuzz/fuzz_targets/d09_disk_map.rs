@@ -0,0 +1,16 @@
+#![no_main]
+
+use d09::DiskMap;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    if let Ok(disk_map) = DiskMap::parse(input) {
+        disk_map.block_shrink();
+        disk_map.file_shrink();
+        disk_map.to_string();
+    }
+});
@@ -0,0 +1,335 @@
+use std::ops::{Index, IndexMut};
+
+use common::ParseError;
+
+/// A `(row, column)` coordinate inside a grid.
+pub type Position = (usize, usize);
+
+/// A signed `(row, column)` step between two positions.
+pub type Offset = (isize, isize);
+
+/// Up, right, down and left.
+pub const ORTHOGONAL: [Offset; 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Every horizontal, vertical and diagonal step.
+pub const DIRECTIONS: [Offset; 8] = [
+    (0, 1),
+    (0, -1),
+    (-1, 0),
+    (1, 0),
+    (1, 1),
+    (1, -1),
+    (-1, -1),
+    (-1, 1),
+];
+
+/// Moves `position` by `offset` without regard for the edges of any grid,
+/// returning `None` only if a coordinate would go negative.
+pub fn step((row, col): Position, (dr, dc): Offset) -> Option<Position> {
+    Some((row.checked_add_signed(dr)?, col.checked_add_signed(dc)?))
+}
+
+/// A heading along one of the four orthogonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rotation {
+    Clockwise,
+    CounterClockwise,
+}
+
+impl Direction {
+    /// Every direction, clockwise from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn offset(self) -> Offset {
+        match self {
+            Direction::Left => (0, -1),
+            Direction::Right => (0, 1),
+            Direction::Up => (-1, 0),
+            Direction::Down => (1, 0),
+        }
+    }
+
+    pub fn rotate(self, direction: Rotation) -> Self {
+        match (self, direction) {
+            (Self::Left, Rotation::Clockwise) => Self::Up,
+            (Self::Left, Rotation::CounterClockwise) => Self::Down,
+            (Self::Up, Rotation::Clockwise) => Self::Right,
+            (Self::Up, Rotation::CounterClockwise) => Self::Left,
+            (Self::Right, Rotation::Clockwise) => Self::Down,
+            (Self::Right, Rotation::CounterClockwise) => Self::Up,
+            (Self::Down, Rotation::Clockwise) => Self::Left,
+            (Self::Down, Rotation::CounterClockwise) => Self::Right,
+        }
+    }
+}
+
+/// Rectangular grid stored row-major in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(rows: usize, cols: usize, cells: Vec<T>) -> Self {
+        assert_eq!(rows * cols, cells.len(), "grid must be {rows}x{cols}");
+
+        Self { cells, rows, cols }
+    }
+
+    pub fn filled(rows: usize, cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(rows, cols, vec![value; rows * cols])
+    }
+
+    /// Builds a grid from text, mapping every character with `cell`.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Result<Self, ParseError> {
+        Self::try_parse(input, |c| Ok(cell(c)))
+    }
+
+    /// Builds a grid from text, mapping every character with `cell`, which
+    /// rejects a character by returning the reason it is invalid.
+    pub fn try_parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> Result<T, &'static str>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::with_capacity(input.len());
+        let mut rows = 0;
+        let mut cols = 0;
+
+        for (row, line) in input.lines().enumerate() {
+            let before = cells.len();
+
+            for (col, c) in line.chars().enumerate() {
+                let value = cell(c)
+                    .map_err(|reason| ParseError::new(row + 1, col + 1, &c.to_string(), reason))?;
+                cells.push(value);
+            }
+
+            let found = cells.len() - before;
+
+            if row == 0 {
+                cols = found;
+            } else if found != cols {
+                return Err(ParseError::new(
+                    row + 1,
+                    found.min(cols) + 1,
+                    line,
+                    &format!("expected {cols} columns but found {found}"),
+                ));
+            }
+
+            rows += 1;
+        }
+
+        Ok(Self { cells, rows, cols })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn contains(&self, (row, col): Position) -> bool {
+        row < self.rows && col < self.cols
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        if self.contains(position) {
+            Some(&self.cells[position.0 * self.cols + position.1])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if self.contains(position) {
+            Some(&mut self.cells[position.0 * self.cols + position.1])
+        } else {
+            None
+        }
+    }
+
+    /// Moves `position` by `offset`, returning `None` if that leaves the grid.
+    pub fn offset(&self, position: Position, offset: Offset) -> Option<Position> {
+        step(position, offset).filter(|&position| self.contains(position))
+    }
+
+    /// The cell `offset` away from `position`, if it is inside the grid.
+    pub fn neighbour(&self, position: Position, offset: Offset) -> Option<&T> {
+        self.offset(position, offset).and_then(|p| self.get(p))
+    }
+
+    /// Orthogonally adjacent positions that lie inside the grid.
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |offset| self.offset(position, offset))
+    }
+
+    /// Orthogonally and diagonally adjacent positions that lie inside the grid.
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        DIRECTIONS
+            .into_iter()
+            .filter_map(move |offset| self.offset(position, offset))
+    }
+
+    /// Every position in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |row| (0..cols).map(move |col| (row, col)))
+    }
+
+    /// Every cell alongside its position, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Position of the first cell matching `predicate`, in row-major order.
+    pub fn find(&self, predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|idx| (idx / self.cols, idx % self.cols))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        match self.get(position) {
+            Some(cell) => cell,
+            None => panic!(
+                "position {position:?} is outside a {}x{} grid",
+                self.rows, self.cols
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        let (rows, cols) = (self.rows, self.cols);
+        match self.get_mut(position) {
+            Some(cell) => cell,
+            None => panic!("position {position:?} is outside a {rows}x{cols} grid"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::ParseError;
+
+    use super::{Direction, Grid, Rotation, ORTHOGONAL};
+
+    static SAMPLE: &str = "abc\ndef";
+
+    #[test]
+    fn parse_maps_characters() {
+        let grid = Grid::parse(SAMPLE, |c| c.to_ascii_uppercase()).unwrap();
+
+        assert_eq!(2, grid.rows());
+        assert_eq!(3, grid.cols());
+        assert_eq!('A', grid[(0, 0)]);
+        assert_eq!('F', grid[(1, 2)]);
+    }
+
+    #[test]
+    fn parse_rejects_ragged_rows() {
+        let error = Grid::parse("abc\nde\nfgh", |c| c).unwrap_err();
+
+        assert_eq!(
+            ParseError::new(2, 3, "de", "expected 3 columns but found 2"),
+            error
+        );
+    }
+
+    #[test]
+    fn try_parse_reports_rejected_character() {
+        let error = Grid::try_parse("ab\ncd", |c| match c {
+            'a'..='c' => Ok(c),
+            _ => Err("unexpected character"),
+        })
+        .unwrap_err();
+
+        assert_eq!(ParseError::new(2, 2, "d", "unexpected character"), error);
+    }
+
+    #[test]
+    fn bounds_checked_access() {
+        let grid = Grid::parse(SAMPLE, |c| c).unwrap();
+
+        assert_eq!(Some(&'e'), grid.get((1, 1)));
+        assert_eq!(None, grid.get((2, 0)));
+        assert_eq!(None, grid.get((0, 3)));
+        assert_eq!(Some(&'b'), grid.neighbour((1, 1), (-1, 0)));
+        assert_eq!(None, grid.neighbour((0, 0), (-1, 0)));
+        assert_eq!(None, grid.offset((0, 2), (0, 1)));
+        assert_eq!(Some((0, 3)), super::step((0, 2), (0, 1)));
+        assert_eq!(None, super::step((0, 2), (-1, 0)));
+    }
+
+    #[test]
+    fn neighbourhoods_stay_inside() {
+        let grid = Grid::filled(3, 3, 0);
+
+        assert_eq!(2, grid.neighbours4((0, 0)).count());
+        assert_eq!(4, grid.neighbours4((1, 1)).count());
+        assert_eq!(3, grid.neighbours8((0, 0)).count());
+        assert_eq!(8, grid.neighbours8((1, 1)).count());
+    }
+
+    #[test]
+    fn iteration_is_row_major() {
+        let grid = Grid::parse(SAMPLE, |c| c).unwrap();
+
+        let cells = grid.iter().map(|(p, c)| (p, *c)).collect::<Vec<_>>();
+
+        assert_eq!(((0, 0), 'a'), cells[0]);
+        assert_eq!(((0, 2), 'c'), cells[2]);
+        assert_eq!(((1, 0), 'd'), cells[3]);
+        assert_eq!(Some((1, 1)), grid.find(|&c| c == 'e'));
+    }
+
+    #[test]
+    fn directions_turn_in_order() {
+        assert_eq!(ORTHOGONAL, Direction::ALL.map(Direction::offset));
+
+        for (direction, next) in Direction::ALL
+            .into_iter()
+            .zip(Direction::ALL.into_iter().cycle().skip(1))
+        {
+            assert_eq!(next, direction.rotate(Rotation::Clockwise));
+            assert_eq!(direction, next.rotate(Rotation::CounterClockwise));
+        }
+    }
+}